@@ -0,0 +1,320 @@
+use belmarsh::{
+    dependency::{
+        analysis::DependencyAnalysis,
+        list::{DependencyList, DependencyListFromRepositoryError},
+    },
+    git::{GitError, GitRepository},
+    module::Module,
+    module_mapping::{ModuleMappings, ModuleMappingsFromParamStringsError},
+    repository::{
+        Repository, RepositoryFromStringError,
+        child::{RepositoryChildPathFromPathError, RepositoryChildPathModuleError},
+        path::RepositoryPathFromStringError,
+    },
+};
+use clap::Args;
+use serde::Serialize;
+use std::{
+    collections::HashSet,
+    fmt::Display,
+    io::BufRead,
+    path::{Path, PathBuf},
+};
+
+use super::output::{OutputFormat, dependencies_from_analysis, error_chain, print_json};
+
+#[derive(Args, Debug)]
+#[command(about = "List the modules affected by a set of changed files")]
+pub struct AffectedCommand {
+    repository_path: String,
+
+    #[arg(help = "Changed files, read one per line from stdin when omitted")]
+    files: Vec<PathBuf>,
+
+    #[arg(
+        long,
+        help = "Also treat files changed in the local git repository since this revision as changed",
+        value_name = "REV"
+    )]
+    since: Option<String>,
+
+    #[arg(
+        long,
+        help = "Folders to skip when walking the repository (e.g. node_modules)",
+        value_name = "FOLDER_NAME",
+        default_value = "node_modules"
+    )]
+    skip_folders: Vec<String>,
+
+    #[arg(
+        long,
+        help = "Add a module mapping e.g. --module-mapping @prefix:./path/to/modules",
+        value_name = "ALIAS:PATH"
+    )]
+    module_mapping: Vec<String>,
+
+    #[arg(
+        long,
+        help = "Output format",
+        value_enum,
+        default_value_t = OutputFormat::Text
+    )]
+    format: OutputFormat,
+
+    #[arg(
+        long,
+        help = "Fail when a file cannot be analyzed instead of reporting a partial result"
+    )]
+    strict: bool,
+}
+
+#[derive(Debug)]
+pub enum AffectedCommandError {
+    CouldNotParseRepository(RepositoryFromStringError),
+    CouldNotGetDependencies(DependencyListFromRepositoryError),
+    InvalidRepositoryPath(RepositoryPathFromStringError),
+    CouldNotParseModuleMapCollection(ModuleMappingsFromParamStringsError),
+    CouldNotReadChangedFiles(std::io::Error),
+    CouldNotGetGitChanges(GitError),
+    CouldNotSerializeOutput(serde_json::Error),
+}
+
+impl Display for AffectedCommandError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AffectedCommandError::CouldNotParseRepository(_) => write!(f, "invalid repository"),
+            AffectedCommandError::CouldNotGetDependencies(_) => {
+                write!(f, "could not collect dependencies")
+            }
+            AffectedCommandError::InvalidRepositoryPath(_) => {
+                write!(f, "invalid repository path")
+            }
+            AffectedCommandError::CouldNotParseModuleMapCollection(_) => {
+                write!(f, "invalid --module-mapping")
+            }
+            AffectedCommandError::CouldNotReadChangedFiles(_) => {
+                write!(f, "could not read changed files from stdin")
+            }
+            AffectedCommandError::CouldNotGetGitChanges(_) => {
+                write!(f, "could not list files changed in git")
+            }
+            AffectedCommandError::CouldNotSerializeOutput(_) => {
+                write!(f, "could not serialize output")
+            }
+        }
+    }
+}
+
+impl std::error::Error for AffectedCommandError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            AffectedCommandError::CouldNotParseRepository(e) => Some(e),
+            AffectedCommandError::CouldNotGetDependencies(e) => Some(e),
+            AffectedCommandError::InvalidRepositoryPath(e) => Some(e),
+            AffectedCommandError::CouldNotParseModuleMapCollection(e) => Some(e),
+            AffectedCommandError::CouldNotReadChangedFiles(e) => Some(e),
+            AffectedCommandError::CouldNotGetGitChanges(e) => Some(e),
+            AffectedCommandError::CouldNotSerializeOutput(e) => Some(e),
+        }
+    }
+}
+
+impl From<serde_json::Error> for AffectedCommandError {
+    fn from(err: serde_json::Error) -> Self {
+        AffectedCommandError::CouldNotSerializeOutput(err)
+    }
+}
+
+impl From<RepositoryFromStringError> for AffectedCommandError {
+    fn from(err: RepositoryFromStringError) -> Self {
+        AffectedCommandError::CouldNotParseRepository(err)
+    }
+}
+
+impl From<DependencyListFromRepositoryError> for AffectedCommandError {
+    fn from(value: DependencyListFromRepositoryError) -> Self {
+        AffectedCommandError::CouldNotGetDependencies(value)
+    }
+}
+
+impl From<RepositoryPathFromStringError> for AffectedCommandError {
+    fn from(err: RepositoryPathFromStringError) -> Self {
+        AffectedCommandError::InvalidRepositoryPath(err)
+    }
+}
+
+impl From<ModuleMappingsFromParamStringsError> for AffectedCommandError {
+    fn from(err: ModuleMappingsFromParamStringsError) -> Self {
+        AffectedCommandError::CouldNotParseModuleMapCollection(err)
+    }
+}
+
+impl From<std::io::Error> for AffectedCommandError {
+    fn from(err: std::io::Error) -> Self {
+        AffectedCommandError::CouldNotReadChangedFiles(err)
+    }
+}
+
+impl From<GitError> for AffectedCommandError {
+    fn from(err: GitError) -> Self {
+        AffectedCommandError::CouldNotGetGitChanges(err)
+    }
+}
+
+#[derive(Debug)]
+pub enum ChangedFileModuleError {
+    OutsideRepository(RepositoryChildPathFromPathError),
+    NoModule(RepositoryChildPathModuleError),
+}
+
+impl Display for ChangedFileModuleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ChangedFileModuleError::OutsideRepository(_) => {
+                write!(f, "file is outside the repository")
+            }
+            ChangedFileModuleError::NoModule(_) => write!(f, "could not map file to a module"),
+        }
+    }
+}
+
+impl std::error::Error for ChangedFileModuleError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ChangedFileModuleError::OutsideRepository(e) => Some(e),
+            ChangedFileModuleError::NoModule(e) => Some(e),
+        }
+    }
+}
+
+impl From<RepositoryChildPathFromPathError> for ChangedFileModuleError {
+    fn from(err: RepositoryChildPathFromPathError) -> Self {
+        ChangedFileModuleError::OutsideRepository(err)
+    }
+}
+
+impl From<RepositoryChildPathModuleError> for ChangedFileModuleError {
+    fn from(err: RepositoryChildPathModuleError) -> Self {
+        ChangedFileModuleError::NoModule(err)
+    }
+}
+
+/// The module of a changed file, which may have been deleted.
+fn changed_file_module(
+    repository: &Repository,
+    path: &Path,
+) -> Result<Module, ChangedFileModuleError> {
+    Ok(repository.child_path(path)?.module()?)
+}
+
+#[derive(Serialize)]
+struct JsonSkippedFile {
+    file: String,
+    message: String,
+}
+
+#[derive(Serialize)]
+struct AffectedJson {
+    changed_modules: Vec<String>,
+    affected_modules: Vec<String>,
+    skipped_files: Vec<JsonSkippedFile>,
+}
+
+fn sorted_names<'a, TIterator: IntoIterator<Item = &'a Module>>(modules: TIterator) -> Vec<String> {
+    let mut names: Vec<String> = modules
+        .into_iter()
+        .map(|module| module.to_string())
+        .collect();
+    names.sort();
+    names
+}
+
+impl AffectedCommand {
+    pub fn run(self) -> Result<(), AffectedCommandError> {
+        let module_mappings: ModuleMappings =
+            ModuleMappings::from_param_strings(self.module_mapping)?;
+
+        let repository: Repository = Repository::new(
+            self.repository_path.try_into()?,
+            module_mappings,
+            self.skip_folders,
+        );
+
+        let mut files: Vec<PathBuf> = if self.files.is_empty() && self.since.is_none() {
+            std::io::stdin()
+                .lock()
+                .lines()
+                .map(|line| line.map(|line| PathBuf::from(line.trim())))
+                .filter(|path| {
+                    path.as_ref()
+                        .map_or(true, |path| !path.as_os_str().is_empty())
+                })
+                .collect::<Result<Vec<PathBuf>, std::io::Error>>()?
+        } else {
+            self.files
+        };
+
+        if let Some(revision) = &self.since {
+            files.extend(
+                GitRepository::discover(repository.path().as_ref())?
+                    .changed_files_since(revision)?
+                    .into_iter()
+                    .filter(|file| file.starts_with(repository.path())),
+            );
+        }
+
+        let mut changed_modules: HashSet<Module> = HashSet::new();
+        let mut skipped_files: Vec<(PathBuf, ChangedFileModuleError)> = vec![];
+
+        // Only TypeScript files belong to modules, deleted ones included
+        for file in files
+            .into_iter()
+            .filter(|file| file.extension().is_some_and(|extension| extension == "ts"))
+        {
+            match changed_file_module(&repository, &file) {
+                Ok(module) => {
+                    changed_modules.insert(module);
+                }
+                Err(e) => skipped_files.push((file, e)),
+            }
+        }
+
+        let dependencies: DependencyList<Module, Module> =
+            dependencies_from_analysis(DependencyAnalysis::from(repository), self.strict)?;
+        let affected_modules: HashSet<Module> = dependencies
+            .reversed()
+            .reachable_from(changed_modules.iter().cloned());
+
+        match self.format {
+            OutputFormat::Text => {
+                for (file, error) in skipped_files.iter() {
+                    eprintln!(
+                        "Warning: skipping {}: {}",
+                        file.display(),
+                        error_chain(error)
+                    );
+                }
+
+                for module in sorted_names(affected_modules.iter()) {
+                    println!("{}", module);
+                }
+            }
+            OutputFormat::Json => print_json(
+                "affected",
+                AffectedJson {
+                    changed_modules: sorted_names(changed_modules.iter()),
+                    affected_modules: sorted_names(affected_modules.iter()),
+                    skipped_files: skipped_files
+                        .iter()
+                        .map(|(file, error)| JsonSkippedFile {
+                            file: file.display().to_string(),
+                            message: error_chain(error),
+                        })
+                        .collect(),
+                },
+            )?,
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,275 @@
+use belmarsh::{
+    dependency::{
+        analysis::DependencyAnalysis,
+        diff::DependencyListDiff,
+        list::{DependencyList, DependencyListFromRepositoryError},
+    },
+    git::{GitError, GitRepository, GitWorktree},
+    module::Module,
+    module_mapping::{ModuleMappings, ModuleMappingsFromParamStringsError},
+    repository::{Repository, path::RepositoryPathFromStringError},
+};
+use clap::Args;
+use serde::Serialize;
+use std::{
+    fmt::Display,
+    path::{Path, PathBuf},
+};
+
+use super::output::{JsonDependency, OutputFormat, dependencies_from_analysis, print_json};
+
+#[derive(Args, Debug)]
+#[command(about = "Compare the module dependencies of two directories or two git revisions")]
+pub struct DiffCommand {
+    #[arg(help = "Directory, or revision with --git, to compare from")]
+    before: String,
+
+    #[arg(help = "Directory, or revision with --git, to compare to")]
+    after: String,
+
+    #[arg(
+        long,
+        help = "Treat BEFORE and AFTER as revisions and compare this directory as checked out at each of them from the local git repository",
+        value_name = "DIR"
+    )]
+    git: Option<PathBuf>,
+
+    #[arg(
+        long,
+        help = "Folders to skip when walking the repository (e.g. node_modules)",
+        value_name = "FOLDER_NAME",
+        default_value = "node_modules"
+    )]
+    skip_folders: Vec<String>,
+
+    #[arg(
+        long,
+        help = "Add a module mapping e.g. --module-mapping @prefix:./path/to/modules",
+        value_name = "ALIAS:PATH"
+    )]
+    module_mapping: Vec<String>,
+
+    #[arg(
+        long,
+        help = "Output format",
+        value_enum,
+        default_value_t = OutputFormat::Text
+    )]
+    format: OutputFormat,
+
+    #[arg(
+        long,
+        help = "Fail when a file cannot be analyzed instead of reporting a partial result"
+    )]
+    strict: bool,
+}
+
+#[derive(Debug)]
+pub enum DiffCommandError {
+    CouldNotGetDependencies(DependencyListFromRepositoryError),
+    InvalidRepositoryPath(RepositoryPathFromStringError),
+    CouldNotParseModuleMapCollection(ModuleMappingsFromParamStringsError),
+    CouldNotCheckOutRevision(GitError),
+    CouldNotSerializeOutput(serde_json::Error),
+}
+
+impl Display for DiffCommandError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DiffCommandError::CouldNotGetDependencies(_) => {
+                write!(f, "could not collect dependencies")
+            }
+            DiffCommandError::InvalidRepositoryPath(_) => write!(f, "invalid repository path"),
+            DiffCommandError::CouldNotParseModuleMapCollection(_) => {
+                write!(f, "invalid --module-mapping")
+            }
+            DiffCommandError::CouldNotCheckOutRevision(_) => {
+                write!(f, "could not check out revision")
+            }
+            DiffCommandError::CouldNotSerializeOutput(_) => {
+                write!(f, "could not serialize output")
+            }
+        }
+    }
+}
+
+impl std::error::Error for DiffCommandError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            DiffCommandError::CouldNotGetDependencies(e) => Some(e),
+            DiffCommandError::InvalidRepositoryPath(e) => Some(e),
+            DiffCommandError::CouldNotParseModuleMapCollection(e) => Some(e),
+            DiffCommandError::CouldNotCheckOutRevision(e) => Some(e),
+            DiffCommandError::CouldNotSerializeOutput(e) => Some(e),
+        }
+    }
+}
+
+impl From<serde_json::Error> for DiffCommandError {
+    fn from(err: serde_json::Error) -> Self {
+        DiffCommandError::CouldNotSerializeOutput(err)
+    }
+}
+
+impl From<DependencyListFromRepositoryError> for DiffCommandError {
+    fn from(value: DependencyListFromRepositoryError) -> Self {
+        DiffCommandError::CouldNotGetDependencies(value)
+    }
+}
+
+impl From<RepositoryPathFromStringError> for DiffCommandError {
+    fn from(err: RepositoryPathFromStringError) -> Self {
+        DiffCommandError::InvalidRepositoryPath(err)
+    }
+}
+
+impl From<ModuleMappingsFromParamStringsError> for DiffCommandError {
+    fn from(err: ModuleMappingsFromParamStringsError) -> Self {
+        DiffCommandError::CouldNotParseModuleMapCollection(err)
+    }
+}
+
+impl From<GitError> for DiffCommandError {
+    fn from(err: GitError) -> Self {
+        DiffCommandError::CouldNotCheckOutRevision(err)
+    }
+}
+
+#[derive(Serialize)]
+struct JsonFanChange {
+    module: String,
+    fan_in_before: usize,
+    fan_in_after: usize,
+    fan_out_before: usize,
+    fan_out_after: usize,
+}
+
+#[derive(Serialize)]
+struct DiffJson {
+    added: Vec<JsonDependency>,
+    removed: Vec<JsonDependency>,
+    new_cycles: Vec<Vec<String>>,
+    fan_changes: Vec<JsonFanChange>,
+}
+
+impl DiffCommand {
+    fn dependencies(
+        &self,
+        path: &Path,
+    ) -> Result<DependencyList<Module, Module>, DiffCommandError> {
+        let module_mappings: ModuleMappings =
+            ModuleMappings::from_param_strings(self.module_mapping.clone())?;
+
+        let repository: Repository = Repository::new(
+            path.display().to_string().try_into()?,
+            module_mappings,
+            self.skip_folders.clone(),
+        );
+
+        Ok(dependencies_from_analysis(
+            DependencyAnalysis::from(repository),
+            self.strict,
+        )?)
+    }
+
+    fn revision_dependencies(
+        &self,
+        directory: &Path,
+        revision: &str,
+    ) -> Result<DependencyList<Module, Module>, DiffCommandError> {
+        let directory = directory.canonicalize().map_err(|e| {
+            RepositoryPathFromStringError::IoError(e, directory.display().to_string())
+        })?;
+        let git_repository = GitRepository::discover(&directory)?;
+        let relative_directory = directory
+            .strip_prefix(git_repository.toplevel())
+            .unwrap_or(Path::new(""));
+
+        let worktree: GitWorktree = git_repository.checkout(revision)?;
+
+        self.dependencies(&worktree.path().join(relative_directory))
+    }
+
+    pub fn run(self) -> Result<(), DiffCommandError> {
+        let (before, after) = match &self.git {
+            Some(directory) => (
+                self.revision_dependencies(directory, &self.before)?,
+                self.revision_dependencies(directory, &self.after)?,
+            ),
+            None => (
+                self.dependencies(Path::new(&self.before))?,
+                self.dependencies(Path::new(&self.after))?,
+            ),
+        };
+
+        let diff = DependencyListDiff::between(&before, &after);
+
+        match self.format {
+            OutputFormat::Text => {
+                println!("\nAdded module dependencies");
+                for dependency in diff.added.iter() {
+                    println!("{}", dependency);
+                }
+                println!("\n\nTotal: {}", diff.added.len());
+
+                println!("\nRemoved module dependencies");
+                for dependency in diff.removed.iter() {
+                    println!("{}", dependency);
+                }
+                println!("\n\nTotal: {}", diff.removed.len());
+
+                println!("\nNew circular dependencies");
+                for cycle in diff.new_cycles.iter() {
+                    println!("{}", cycle);
+                }
+                println!("\n\nTotal: {}", diff.new_cycles.len());
+
+                println!("\nFan-in and fan-out changes");
+                for change in diff.fan_changes.iter() {
+                    println!(
+                        "{}: fan-in {} -> {}, fan-out {} -> {}",
+                        change.node,
+                        change.fan_in.0,
+                        change.fan_in.1,
+                        change.fan_out.0,
+                        change.fan_out.1
+                    );
+                }
+                println!("\n\nTotal: {}", diff.fan_changes.len());
+            }
+            OutputFormat::Json => print_json(
+                "diff",
+                DiffJson {
+                    added: diff
+                        .added
+                        .iter()
+                        .map(JsonDependency::from_dependency)
+                        .collect(),
+                    removed: diff
+                        .removed
+                        .iter()
+                        .map(JsonDependency::from_dependency)
+                        .collect(),
+                    new_cycles: diff
+                        .new_cycles
+                        .iter()
+                        .map(|cycle| cycle.0.clone())
+                        .collect(),
+                    fan_changes: diff
+                        .fan_changes
+                        .iter()
+                        .map(|change| JsonFanChange {
+                            module: change.node.to_string(),
+                            fan_in_before: change.fan_in.0,
+                            fan_in_after: change.fan_in.1,
+                            fan_out_before: change.fan_out.0,
+                            fan_out_after: change.fan_out.1,
+                        })
+                        .collect(),
+                },
+            )?,
+        }
+
+        Ok(())
+    }
+}
@@ -1,10 +1,17 @@
 use belmarsh::{
-    dependency::list::{DependencyList, DependencyListFromRepositoryError},
+    dependency::{
+        analysis::DependencyAnalysis,
+        list::{DependencyList, DependencyListFromRepositoryError},
+    },
     module::Module,
     module_mapping::{ModuleMappings, ModuleMappingsFromParamStringsError},
     repository::{Repository, RepositoryFromStringError, path::RepositoryPathFromStringError},
 };
 use clap::{Args, command};
+use serde::Serialize;
+use std::fmt::Display;
+
+use super::output::{JsonDependency, OutputFormat, dependencies_from_analysis, print_json};
 
 #[derive(Args, Debug)]
 #[command(about = "Output a dependency graph in the dot format")]
@@ -25,6 +32,20 @@ pub struct GraphCommand {
         value_name = "ALIAS:PATH"
     )]
     module_mapping: Vec<String>,
+
+    #[arg(
+        long,
+        help = "Output format",
+        value_enum,
+        default_value_t = OutputFormat::Text
+    )]
+    format: OutputFormat,
+
+    #[arg(
+        long,
+        help = "Fail when a file cannot be analyzed instead of reporting a partial result"
+    )]
+    strict: bool,
 }
 
 #[derive(Debug)]
@@ -33,6 +54,45 @@ pub enum GraphCommandError {
     CouldNotGetDependencies(DependencyListFromRepositoryError),
     CouldNotCreateRepositoryPath(RepositoryPathFromStringError),
     CouldNotParseModuleMapCollection(ModuleMappingsFromParamStringsError),
+    CouldNotSerializeOutput(serde_json::Error),
+}
+
+impl Display for GraphCommandError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GraphCommandError::CouldNotParseRepository(_) => write!(f, "invalid repository"),
+            GraphCommandError::CouldNotGetDependencies(_) => {
+                write!(f, "could not collect dependencies")
+            }
+            GraphCommandError::CouldNotCreateRepositoryPath(_) => {
+                write!(f, "invalid repository path")
+            }
+            GraphCommandError::CouldNotParseModuleMapCollection(_) => {
+                write!(f, "invalid --module-mapping")
+            }
+            GraphCommandError::CouldNotSerializeOutput(_) => {
+                write!(f, "could not serialize output")
+            }
+        }
+    }
+}
+
+impl std::error::Error for GraphCommandError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            GraphCommandError::CouldNotParseRepository(e) => Some(e),
+            GraphCommandError::CouldNotGetDependencies(e) => Some(e),
+            GraphCommandError::CouldNotCreateRepositoryPath(e) => Some(e),
+            GraphCommandError::CouldNotParseModuleMapCollection(e) => Some(e),
+            GraphCommandError::CouldNotSerializeOutput(e) => Some(e),
+        }
+    }
+}
+
+impl From<serde_json::Error> for GraphCommandError {
+    fn from(err: serde_json::Error) -> Self {
+        GraphCommandError::CouldNotSerializeOutput(err)
+    }
 }
 
 impl From<RepositoryFromStringError> for GraphCommandError {
@@ -59,6 +119,11 @@ impl From<ModuleMappingsFromParamStringsError> for GraphCommandError {
     }
 }
 
+#[derive(Serialize)]
+struct GraphJson {
+    dependencies: Vec<JsonDependency>,
+}
+
 impl GraphCommand {
     pub fn run(self) -> Result<(), GraphCommandError> {
         let module_mappings: ModuleMappings =
@@ -69,7 +134,19 @@ impl GraphCommand {
             module_mappings,
             self.skip_folders,
         );
-        let dependencies: DependencyList<Module, Module> = repository.try_into()?;
+        let dependencies: DependencyList<Module, Module> =
+            dependencies_from_analysis(DependencyAnalysis::from(repository), self.strict)?;
+
+        if self.format == OutputFormat::Json {
+            print_json(
+                "graph",
+                GraphJson {
+                    dependencies: JsonDependency::from_dependencies(&dependencies),
+                },
+            )?;
+
+            return Ok(());
+        }
 
         println!("digraph G {{");
 
@@ -1,8 +1,12 @@
 use std::collections::HashSet;
+use std::fmt::Display;
 
 use belmarsh::{
     dependency::Dependency,
-    dependency::list::{DependencyList, DependencyListFromRepositoryError},
+    dependency::{
+        analysis::DependencyAnalysis,
+        list::{DependencyList, DependencyListFromRepositoryError},
+    },
     module::Module,
     module_mapping::{ModuleMappings, ModuleMappingsFromParamStringsError},
     repository::{
@@ -11,6 +15,9 @@ use belmarsh::{
     },
 };
 use clap::{Args, command};
+use serde::Serialize;
+
+use super::output::{JsonDependency, OutputFormat, dependencies_from_analysis, print_json};
 
 #[derive(Args, Debug)]
 #[command(about = "Lists all the files that draw in a non-internal dependency")]
@@ -34,6 +41,20 @@ pub struct InspectCommand {
         value_name = "ALIAS:PATH"
     )]
     module_mapping: Vec<String>,
+
+    #[arg(
+        long,
+        help = "Output format",
+        value_enum,
+        default_value_t = OutputFormat::Text
+    )]
+    format: OutputFormat,
+
+    #[arg(
+        long,
+        help = "Fail when a file cannot be analyzed instead of reporting a partial result"
+    )]
+    strict: bool,
 }
 
 #[derive(Debug)]
@@ -42,6 +63,45 @@ pub enum InspectCommandError {
     CouldNotGetDependencies(DependencyListFromRepositoryError),
     CouldNotCreateRepositoryPath(RepositoryPathFromStringError),
     CouldNotParseModuleMapCollection(ModuleMappingsFromParamStringsError),
+    CouldNotSerializeOutput(serde_json::Error),
+}
+
+impl Display for InspectCommandError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InspectCommandError::CouldNotParseRepository(_) => write!(f, "invalid repository"),
+            InspectCommandError::CouldNotGetDependencies(_) => {
+                write!(f, "could not collect dependencies")
+            }
+            InspectCommandError::CouldNotCreateRepositoryPath(_) => {
+                write!(f, "invalid repository path")
+            }
+            InspectCommandError::CouldNotParseModuleMapCollection(_) => {
+                write!(f, "invalid --module-mapping")
+            }
+            InspectCommandError::CouldNotSerializeOutput(_) => {
+                write!(f, "could not serialize output")
+            }
+        }
+    }
+}
+
+impl std::error::Error for InspectCommandError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            InspectCommandError::CouldNotParseRepository(e) => Some(e),
+            InspectCommandError::CouldNotGetDependencies(e) => Some(e),
+            InspectCommandError::CouldNotCreateRepositoryPath(e) => Some(e),
+            InspectCommandError::CouldNotParseModuleMapCollection(e) => Some(e),
+            InspectCommandError::CouldNotSerializeOutput(e) => Some(e),
+        }
+    }
+}
+
+impl From<serde_json::Error> for InspectCommandError {
+    fn from(err: serde_json::Error) -> Self {
+        InspectCommandError::CouldNotSerializeOutput(err)
+    }
 }
 
 impl From<RepositoryFromStringError> for InspectCommandError {
@@ -68,6 +128,11 @@ impl From<ModuleMappingsFromParamStringsError> for InspectCommandError {
     }
 }
 
+#[derive(Serialize)]
+struct InspectJson {
+    dependencies: Vec<JsonDependency>,
+}
+
 impl InspectCommand {
     pub fn run(self) -> Result<(), InspectCommandError> {
         let module_mappings: ModuleMappings =
@@ -78,9 +143,10 @@ impl InspectCommand {
             module_mappings,
             self.skip_folders,
         );
-        let dependencies: DependencyList<RepositoryChildPath, Module> = repository.try_into()?;
+        let dependencies: DependencyList<RepositoryChildPath, Module> =
+            dependencies_from_analysis(DependencyAnalysis::from(repository), self.strict)?;
 
-        if let Some(filter_module_name) = self.filter_from {
+        let dependencies = if let Some(filter_module_name) = self.filter_from {
             let filtered_deps_set: HashSet<Dependency<RepositoryChildPath, Module>> = dependencies
                 .as_ref()
                 .iter()
@@ -88,12 +154,68 @@ impl InspectCommand {
                 .cloned()
                 .collect();
 
-            let filtered_deps_list = DependencyList::from(filtered_deps_set);
-            println!("{}", filtered_deps_list);
+            DependencyList::from(filtered_deps_set)
         } else {
-            println!("{}", dependencies);
+            dependencies
+        };
+
+        match self.format {
+            OutputFormat::Text => {
+                for dependency in dependencies.as_ref().iter() {
+                    println!("{} > {}", dependency.located_from(), dependency.to);
+                }
+            }
+            OutputFormat::Json => print_json(
+                "inspect",
+                InspectJson {
+                    dependencies: JsonDependency::from_dependencies(&dependencies),
+                },
+            )?,
         }
 
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use belmarsh::import_path::ImportLocation;
+
+    use super::super::output::json_document;
+    use super::*;
+
+    #[test]
+    fn json_output_keeps_its_shape() {
+        let dependencies: DependencyList<String, String> = HashSet::from([Dependency::create_at(
+            "checkout/cart.ts".to_string(),
+            "payment".to_string(),
+            ImportLocation::new(3, 22, "../payment".to_string()),
+        )])
+        .into();
+
+        assert_eq!(
+            json_document(
+                "inspect",
+                InspectJson {
+                    dependencies: JsonDependency::from_dependencies(&dependencies),
+                },
+            )
+            .unwrap(),
+            r#"{
+  "schema_version": 1,
+  "command": "inspect",
+  "dependencies": [
+    {
+      "from": "checkout/cart.ts",
+      "to": "payment",
+      "location": {
+        "line": 3,
+        "column": 22,
+        "specifier": "../payment"
+      }
+    }
+  ]
+}"#
+        );
+    }
+}
@@ -0,0 +1,185 @@
+use belmarsh::{
+    dependency::{
+        analysis::DependencyAnalysis,
+        list::{DependencyList, DependencyListFromRepositoryError},
+    },
+    module::Module,
+    module_mapping::{ModuleMappings, ModuleMappingsFromParamStringsError},
+    repository::{Repository, RepositoryFromStringError, path::RepositoryPathFromStringError},
+};
+use clap::Args;
+use serde::Serialize;
+use std::fmt::Display;
+
+use super::output::{OutputFormat, dependencies_from_analysis, print_json};
+
+#[derive(Args, Debug)]
+#[command(
+    about = "List modules in build order with their level, the length of their longest dependency chain"
+)]
+pub struct LayersCommand {
+    repository_path: String,
+
+    #[arg(
+        long,
+        help = "Folders to skip when walking the repository (e.g. node_modules)",
+        value_name = "FOLDER_NAME",
+        default_value = "node_modules"
+    )]
+    skip_folders: Vec<String>,
+
+    #[arg(
+        long,
+        help = "Add a module mapping e.g. --module-mapping @prefix:./path/to/modules",
+        value_name = "ALIAS:PATH"
+    )]
+    module_mapping: Vec<String>,
+
+    #[arg(
+        long,
+        help = "Output format",
+        value_enum,
+        default_value_t = OutputFormat::Text
+    )]
+    format: OutputFormat,
+
+    #[arg(
+        long,
+        help = "Fail when a file cannot be analyzed instead of reporting a partial result"
+    )]
+    strict: bool,
+}
+
+#[derive(Debug)]
+pub enum LayersCommandError {
+    CouldNotParseRepository(RepositoryFromStringError),
+    CouldNotGetDependencies(DependencyListFromRepositoryError),
+    InvalidRepositoryPath(RepositoryPathFromStringError),
+    CouldNotParseModuleMapCollection(ModuleMappingsFromParamStringsError),
+    CouldNotSerializeOutput(serde_json::Error),
+}
+
+impl Display for LayersCommandError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LayersCommandError::CouldNotParseRepository(_) => write!(f, "invalid repository"),
+            LayersCommandError::CouldNotGetDependencies(_) => {
+                write!(f, "could not collect dependencies")
+            }
+            LayersCommandError::InvalidRepositoryPath(_) => {
+                write!(f, "invalid repository path")
+            }
+            LayersCommandError::CouldNotParseModuleMapCollection(_) => {
+                write!(f, "invalid --module-mapping")
+            }
+            LayersCommandError::CouldNotSerializeOutput(_) => {
+                write!(f, "could not serialize output")
+            }
+        }
+    }
+}
+
+impl std::error::Error for LayersCommandError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            LayersCommandError::CouldNotParseRepository(e) => Some(e),
+            LayersCommandError::CouldNotGetDependencies(e) => Some(e),
+            LayersCommandError::InvalidRepositoryPath(e) => Some(e),
+            LayersCommandError::CouldNotParseModuleMapCollection(e) => Some(e),
+            LayersCommandError::CouldNotSerializeOutput(e) => Some(e),
+        }
+    }
+}
+
+impl From<serde_json::Error> for LayersCommandError {
+    fn from(err: serde_json::Error) -> Self {
+        LayersCommandError::CouldNotSerializeOutput(err)
+    }
+}
+
+impl From<RepositoryFromStringError> for LayersCommandError {
+    fn from(err: RepositoryFromStringError) -> Self {
+        LayersCommandError::CouldNotParseRepository(err)
+    }
+}
+
+impl From<DependencyListFromRepositoryError> for LayersCommandError {
+    fn from(value: DependencyListFromRepositoryError) -> Self {
+        LayersCommandError::CouldNotGetDependencies(value)
+    }
+}
+
+impl From<RepositoryPathFromStringError> for LayersCommandError {
+    fn from(err: RepositoryPathFromStringError) -> Self {
+        LayersCommandError::InvalidRepositoryPath(err)
+    }
+}
+
+impl From<ModuleMappingsFromParamStringsError> for LayersCommandError {
+    fn from(err: ModuleMappingsFromParamStringsError) -> Self {
+        LayersCommandError::CouldNotParseModuleMapCollection(err)
+    }
+}
+
+/// One entry per strongly connected component, in build order.
+#[derive(Serialize)]
+struct JsonLayer {
+    level: usize,
+    modules: Vec<String>,
+    circular: bool,
+}
+
+#[derive(Serialize)]
+struct LayersJson {
+    layers: Vec<JsonLayer>,
+}
+
+impl LayersCommand {
+    pub fn run(self) -> Result<(), LayersCommandError> {
+        let module_mappings: ModuleMappings =
+            ModuleMappings::from_param_strings(self.module_mapping)?;
+
+        let repository: Repository = Repository::new(
+            self.repository_path.try_into()?,
+            module_mappings,
+            self.skip_folders,
+        );
+        let modules = repository.modules();
+        let dependencies: DependencyList<Module, Module> =
+            dependencies_from_analysis(DependencyAnalysis::from(repository), self.strict)?;
+
+        let levels = dependencies.to_levels(modules);
+
+        match self.format {
+            OutputFormat::Text => {
+                for component in levels.iter() {
+                    println!(
+                        "{} {}{}",
+                        component.level(),
+                        component.members().join(", "),
+                        if component.is_circular() {
+                            " (circular)"
+                        } else {
+                            ""
+                        }
+                    );
+                }
+            }
+            OutputFormat::Json => print_json(
+                "layers",
+                LayersJson {
+                    layers: levels
+                        .iter()
+                        .map(|component| JsonLayer {
+                            level: component.level(),
+                            modules: component.members().to_vec(),
+                            circular: component.is_circular(),
+                        })
+                        .collect(),
+                },
+            )?,
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,388 @@
+use belmarsh::{
+    dependency::{
+        Dependency,
+        analysis::DependencyAnalysis,
+        list::{DependencyList, DependencyListFromRepositoryError},
+    },
+    module::Module,
+    module_mapping::{ModuleMappings, ModuleMappingsFromParamStringsError},
+    repository::{
+        Repository, RepositoryFromStringError, child::RepositoryChildPath,
+        path::RepositoryPathFromStringError,
+    },
+};
+use clap::{Args, ValueEnum};
+use serde::Serialize;
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+};
+
+use super::output::{dependencies_from_analysis, print_json};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum MatrixOutputFormat {
+    Text,
+    Csv,
+    Json,
+}
+
+#[derive(Args, Debug)]
+#[command(about = "Print a dependency structure matrix of file imports between modules")]
+pub struct MatrixCommand {
+    repository_path: String,
+
+    #[arg(
+        long,
+        help = "Folders to skip when walking the repository (e.g. node_modules)",
+        value_name = "FOLDER_NAME",
+        default_value = "node_modules"
+    )]
+    skip_folders: Vec<String>,
+
+    #[arg(
+        long,
+        help = "Add a module mapping e.g. --module-mapping @prefix:./path/to/modules",
+        value_name = "ALIAS:PATH"
+    )]
+    module_mapping: Vec<String>,
+
+    #[arg(
+        long,
+        help = "Output format",
+        value_enum,
+        default_value_t = MatrixOutputFormat::Text
+    )]
+    format: MatrixOutputFormat,
+
+    #[arg(
+        long,
+        help = "Fail when a file cannot be analyzed instead of reporting a partial result"
+    )]
+    strict: bool,
+}
+
+#[derive(Debug)]
+pub enum MatrixCommandError {
+    CouldNotParseRepository(RepositoryFromStringError),
+    CouldNotGetDependencies(DependencyListFromRepositoryError),
+    InvalidRepositoryPath(RepositoryPathFromStringError),
+    CouldNotParseModuleMapCollection(ModuleMappingsFromParamStringsError),
+    CouldNotSerializeOutput(serde_json::Error),
+}
+
+impl Display for MatrixCommandError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MatrixCommandError::CouldNotParseRepository(_) => write!(f, "invalid repository"),
+            MatrixCommandError::CouldNotGetDependencies(_) => {
+                write!(f, "could not collect dependencies")
+            }
+            MatrixCommandError::InvalidRepositoryPath(_) => {
+                write!(f, "invalid repository path")
+            }
+            MatrixCommandError::CouldNotParseModuleMapCollection(_) => {
+                write!(f, "invalid --module-mapping")
+            }
+            MatrixCommandError::CouldNotSerializeOutput(_) => {
+                write!(f, "could not serialize output")
+            }
+        }
+    }
+}
+
+impl std::error::Error for MatrixCommandError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            MatrixCommandError::CouldNotParseRepository(e) => Some(e),
+            MatrixCommandError::CouldNotGetDependencies(e) => Some(e),
+            MatrixCommandError::InvalidRepositoryPath(e) => Some(e),
+            MatrixCommandError::CouldNotParseModuleMapCollection(e) => Some(e),
+            MatrixCommandError::CouldNotSerializeOutput(e) => Some(e),
+        }
+    }
+}
+
+impl From<serde_json::Error> for MatrixCommandError {
+    fn from(err: serde_json::Error) -> Self {
+        MatrixCommandError::CouldNotSerializeOutput(err)
+    }
+}
+
+impl From<RepositoryFromStringError> for MatrixCommandError {
+    fn from(err: RepositoryFromStringError) -> Self {
+        MatrixCommandError::CouldNotParseRepository(err)
+    }
+}
+
+impl From<DependencyListFromRepositoryError> for MatrixCommandError {
+    fn from(value: DependencyListFromRepositoryError) -> Self {
+        MatrixCommandError::CouldNotGetDependencies(value)
+    }
+}
+
+impl From<RepositoryPathFromStringError> for MatrixCommandError {
+    fn from(err: RepositoryPathFromStringError) -> Self {
+        MatrixCommandError::InvalidRepositoryPath(err)
+    }
+}
+
+impl From<ModuleMappingsFromParamStringsError> for MatrixCommandError {
+    fn from(err: ModuleMappingsFromParamStringsError) -> Self {
+        MatrixCommandError::CouldNotParseModuleMapCollection(err)
+    }
+}
+
+/// Rows import from columns. Modules are in build order, so dependencies
+/// fall below the diagonal and only imports inside a cycle end up above it.
+struct DependencyMatrix {
+    modules: Vec<String>,
+    cells: Vec<Vec<usize>>,
+    circular_blocks: Vec<Vec<String>>,
+}
+
+impl DependencyMatrix {
+    fn new(
+        import_counts: HashMap<Dependency<Module, Module>, usize>,
+        modules: Vec<Module>,
+    ) -> Self {
+        let module_dependencies: DependencyList<Module, Module> = import_counts
+            .keys()
+            .cloned()
+            .collect::<HashSet<Dependency<Module, Module>>>()
+            .into();
+        let levels = module_dependencies.to_levels(modules);
+
+        let modules: Vec<String> = levels
+            .iter()
+            .flat_map(|component| component.members().iter().cloned())
+            .collect();
+        let cells: Vec<Vec<usize>> = modules
+            .iter()
+            .map(|from| {
+                modules
+                    .iter()
+                    .map(|to| {
+                        import_counts
+                            .get(&Dependency::create(
+                                Module::from(from.clone()),
+                                Module::from(to.clone()),
+                            ))
+                            .copied()
+                            .unwrap_or_default()
+                    })
+                    .collect()
+            })
+            .collect();
+        let circular_blocks: Vec<Vec<String>> = levels
+            .iter()
+            .filter(|component| component.members().len() > 1)
+            .map(|component| component.members().to_vec())
+            .collect();
+
+        DependencyMatrix {
+            modules,
+            cells,
+            circular_blocks,
+        }
+    }
+
+    fn print_text(&self) {
+        let index_width = self.modules.len().to_string().len();
+        let name_width = self
+            .modules
+            .iter()
+            .map(|module| module.chars().count())
+            .max()
+            .unwrap_or_default();
+        let cell_width = self
+            .cells
+            .iter()
+            .flatten()
+            .map(|count| count.to_string().len())
+            .max()
+            .unwrap_or_default()
+            .max(index_width);
+
+        let header: Vec<String> = (1..=self.modules.len())
+            .map(|index| format!("{:>width$}", index, width = cell_width))
+            .collect();
+        println!(
+            "{:name_width$} {:index_width$} | {}",
+            "",
+            "",
+            header.join(" ")
+        );
+
+        for (row, module) in self.modules.iter().enumerate() {
+            let cells: Vec<String> = self.cells[row]
+                .iter()
+                .enumerate()
+                .map(|(column, count)| {
+                    let cell = match (row == column, count) {
+                        (true, _) => "-".to_string(),
+                        (false, 0) => ".".to_string(),
+                        (false, count) => count.to_string(),
+                    };
+
+                    format!("{:>width$}", cell, width = cell_width)
+                })
+                .collect();
+
+            println!(
+                "{:name_width$} {:>index_width$} | {}",
+                module,
+                row + 1,
+                cells.join(" ")
+            );
+        }
+
+        if !self.circular_blocks.is_empty() {
+            println!("\nCircular blocks");
+
+            for block in self.circular_blocks.iter() {
+                println!("{}", block.join(", "));
+            }
+        }
+    }
+
+    fn csv_lines(&self) -> Vec<String> {
+        let header: Vec<String> = std::iter::once("module".to_string())
+            .chain(self.modules.iter().map(|module| escape_csv(module)))
+            .collect();
+
+        std::iter::once(header.join(","))
+            .chain(self.modules.iter().enumerate().map(|(row, module)| {
+                std::iter::once(escape_csv(module))
+                    .chain(self.cells[row].iter().map(|count| count.to_string()))
+                    .collect::<Vec<String>>()
+                    .join(",")
+            }))
+            .collect()
+    }
+
+    fn print_csv(&self) {
+        for line in self.csv_lines() {
+            println!("{}", line);
+        }
+    }
+}
+
+fn escape_csv(value: &str) -> String {
+    if value.contains([',', '"', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[derive(Serialize)]
+struct MatrixJson {
+    modules: Vec<String>,
+    cells: Vec<Vec<usize>>,
+    circular_blocks: Vec<Vec<String>>,
+}
+
+impl MatrixCommand {
+    pub fn run(self) -> Result<(), MatrixCommandError> {
+        let module_mappings: ModuleMappings =
+            ModuleMappings::from_param_strings(self.module_mapping)?;
+
+        let repository: Repository = Repository::new(
+            self.repository_path.try_into()?,
+            module_mappings,
+            self.skip_folders,
+        );
+        let modules: Vec<Module> = repository.modules().into_iter().collect();
+        let file_dependencies: DependencyList<RepositoryChildPath, RepositoryChildPath> =
+            dependencies_from_analysis(DependencyAnalysis::from(repository), self.strict)?;
+
+        let matrix = DependencyMatrix::new(file_dependencies.count_module_imports(), modules);
+
+        match self.format {
+            MatrixOutputFormat::Text => matrix.print_text(),
+            MatrixOutputFormat::Csv => matrix.print_csv(),
+            MatrixOutputFormat::Json => print_json(
+                "matrix",
+                MatrixJson {
+                    modules: matrix.modules,
+                    cells: matrix.cells,
+                    circular_blocks: matrix.circular_blocks,
+                },
+            )?,
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matrix(imports: &[(&str, &str, usize)], modules: &[&str]) -> DependencyMatrix {
+        DependencyMatrix::new(
+            imports
+                .iter()
+                .map(|(from, to, count)| {
+                    (
+                        Dependency::create(
+                            Module::from(from.to_string()),
+                            Module::from(to.to_string()),
+                        ),
+                        *count,
+                    )
+                })
+                .collect(),
+            modules
+                .iter()
+                .map(|module| Module::from(module.to_string()))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn rows_and_columns_follow_build_order() {
+        let matrix = matrix(
+            &[("app", "ui", 2), ("app", "core", 1), ("ui", "core", 3)],
+            &["app", "core", "docs", "ui"],
+        );
+
+        assert_eq!(matrix.modules, vec!["core", "docs", "ui", "app"]);
+        assert_eq!(
+            matrix.cells,
+            vec![
+                vec![0, 0, 0, 0],
+                vec![0, 0, 0, 0],
+                vec![3, 0, 0, 0],
+                vec![1, 0, 2, 0],
+            ]
+        );
+        assert!(matrix.circular_blocks.is_empty());
+    }
+
+    #[test]
+    fn cycles_are_reported_as_circular_blocks() {
+        let matrix = matrix(&[("a", "b", 1), ("b", "a", 4)], &["a", "b"]);
+
+        assert_eq!(matrix.modules, vec!["a", "b"]);
+        assert_eq!(matrix.cells, vec![vec![0, 1], vec![4, 0]]);
+        assert_eq!(matrix.circular_blocks, vec![vec!["a", "b"]]);
+    }
+
+    #[test]
+    fn csv_lines_escape_module_names() {
+        let matrix = matrix(&[("a,b", "c\"d", 1)], &["a,b", "c\"d"]);
+
+        assert_eq!(
+            matrix.csv_lines(),
+            vec!["module,\"c\"\"d\",\"a,b\"", "\"c\"\"d\",0,0", "\"a,b\",1,0",]
+        );
+    }
+
+    #[test]
+    fn escape_csv_leaves_plain_values_alone() {
+        assert_eq!(escape_csv("core"), "core");
+        assert_eq!(escape_csv("multi\nline"), "\"multi\nline\"");
+    }
+}
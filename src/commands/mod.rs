@@ -1,19 +1,46 @@
+pub mod affected;
+pub mod diff;
 pub mod graph;
 pub mod inspect;
+pub mod layers;
+pub mod matrix;
+pub mod output;
 pub mod statistics;
+pub mod transitive;
 pub mod validate;
+pub mod why;
 
 use clap::{Parser, Subcommand};
 
+use crate::commands::affected::AffectedCommand;
+use crate::commands::diff::DiffCommand;
 use crate::commands::graph::GraphCommand;
 use crate::commands::inspect::InspectCommand;
+use crate::commands::layers::LayersCommand;
+use crate::commands::matrix::MatrixCommand;
 use crate::commands::statistics::StatisticsCommand;
+use crate::commands::transitive::TransitiveCommand;
 use crate::commands::validate::ValidateCommand;
+use crate::commands::why::WhyCommand;
 
 #[derive(Parser, Debug)]
 #[command(name = "belmarsh")]
 #[command(about = "A tool for analyzing the inner module dependencies of a repository")]
 pub struct Cli {
+    #[arg(
+        long,
+        global = true,
+        help = "Print the time taken by the command to stderr"
+    )]
+    pub timings: bool,
+
+    #[arg(
+        long,
+        global = true,
+        help = "Print the full chain of causes when an error occurs"
+    )]
+    pub verbose: bool,
+
     #[command(subcommand)]
     pub command: Commands,
 }
@@ -24,4 +51,10 @@ pub enum Commands {
     Validate(ValidateCommand),
     Graph(GraphCommand),
     Inspect(InspectCommand),
+    Why(WhyCommand),
+    Transitive(TransitiveCommand),
+    Affected(AffectedCommand),
+    Diff(DiffCommand),
+    Layers(LayersCommand),
+    Matrix(MatrixCommand),
 }
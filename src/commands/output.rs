@@ -0,0 +1,124 @@
+use std::error::Error;
+use std::fmt::Display;
+
+use belmarsh::dependency::{
+    Dependency,
+    analysis::{DependencyAnalysis, FileDiagnostic},
+    list::{DependencyList, DependencyListFromRepositoryError},
+};
+use clap::ValueEnum;
+use serde::Serialize;
+
+/// Version of the JSON documents printed by `--format json`.
+///
+/// Every document is an object carrying `schema_version` and `command` next to the
+/// command specific fields. The version is bumped whenever a field is removed or
+/// changes meaning; adding fields is not considered a breaking change. The
+/// fields of every command are documented in `docs/json-output.md`.
+pub const JSON_SCHEMA_VERSION: u32 = 1;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    Text,
+    Json,
+}
+
+#[derive(Serialize)]
+struct JsonDocument<'a, T: Serialize> {
+    schema_version: u32,
+    command: &'a str,
+    #[serde(flatten)]
+    body: T,
+}
+
+#[derive(Serialize)]
+pub struct JsonImportLocation {
+    pub line: usize,
+    pub column: usize,
+    pub specifier: String,
+}
+
+#[derive(Serialize)]
+pub struct JsonDependency {
+    pub from: String,
+    pub to: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<JsonImportLocation>,
+}
+
+impl JsonDependency {
+    pub fn from_dependency<TFrom: Display, TTo: Display>(
+        dependency: &Dependency<TFrom, TTo>,
+    ) -> JsonDependency {
+        JsonDependency {
+            from: dependency.from.to_string(),
+            to: dependency.to.to_string(),
+            location: dependency
+                .location
+                .as_ref()
+                .map(|location| JsonImportLocation {
+                    line: location.line(),
+                    column: location.column(),
+                    specifier: location.specifier().to_string(),
+                }),
+        }
+    }
+
+    pub fn from_dependencies<TFrom: Display, TTo: Display>(
+        dependencies: &DependencyList<TFrom, TTo>,
+    ) -> Vec<JsonDependency> {
+        let mut json_dependencies: Vec<JsonDependency> = dependencies
+            .as_ref()
+            .iter()
+            .map(JsonDependency::from_dependency)
+            .collect();
+
+        json_dependencies.sort_by(|a, b| a.from.cmp(&b.from).then_with(|| a.to.cmp(&b.to)));
+        json_dependencies
+    }
+}
+
+pub fn error_chain(error: &dyn Error) -> String {
+    let mut messages = vec![error.to_string()];
+    let mut source = error.source();
+
+    while let Some(cause) = source {
+        messages.push(cause.to_string());
+        source = cause.source();
+    }
+
+    messages.join(": ")
+}
+
+pub fn print_diagnostics(diagnostics: &[FileDiagnostic]) {
+    for diagnostic in diagnostics.iter() {
+        eprintln!("Warning: {}", error_chain(diagnostic));
+    }
+}
+
+pub fn dependencies_from_analysis<TFrom: Display, TTo: Display>(
+    analysis: DependencyAnalysis<TFrom, TTo>,
+    strict: bool,
+) -> Result<DependencyList<TFrom, TTo>, DependencyListFromRepositoryError> {
+    if strict {
+        return analysis.into_strict();
+    }
+
+    print_diagnostics(&analysis.diagnostics);
+
+    Ok(analysis.dependencies)
+}
+
+pub fn json_document<T: Serialize>(command: &str, body: T) -> Result<String, serde_json::Error> {
+    serde_json::to_string_pretty(&JsonDocument {
+        schema_version: JSON_SCHEMA_VERSION,
+        command,
+        body,
+    })
+}
+
+pub fn print_json<T: Serialize>(command: &str, body: T) -> Result<(), serde_json::Error> {
+    println!("{}", json_document(command, body)?);
+
+    Ok(())
+}
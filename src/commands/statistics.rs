@@ -1,19 +1,22 @@
 use belmarsh::{
     dependency::Dependency,
-    file_path::FilePath,
     module_mapping::ModuleMappings,
     module_mapping::ModuleMappingsFromParamStringsError,
     repository::{
         Repository, RepositoryFilesError, RepositoryFromStringError,
         child::{
             RepositoryChildPath, RepositoryChildPathFromImportPathError,
-            RepositoryChildPathModuleError,
+            RepositoryChildPathFromPathError, RepositoryChildPathModuleError,
         },
         file::{RepositoryFileModuleError, RepositoryFileResolveImportsError},
         path::RepositoryPathFromStringError,
     },
 };
 use clap::{Args, command};
+use serde::Serialize;
+use std::fmt::Display;
+
+use super::output::{OutputFormat, print_json};
 use rayon::prelude::*;
 use std::sync::atomic::{AtomicUsize, Ordering};
 
@@ -36,18 +39,72 @@ pub struct StatisticsCommand {
         value_name = "ALIAS:PATH"
     )]
     module_mapping: Vec<String>,
+
+    #[arg(
+        long,
+        help = "Output format",
+        value_enum,
+        default_value_t = OutputFormat::Text
+    )]
+    format: OutputFormat,
 }
 
 #[derive(Debug)]
 pub enum StatisticsCommandError {
     CouldNotParseRepository(RepositoryFromStringError),
     CouldNotGetFiles(RepositoryFilesError),
-    InvalidImport(RepositoryChildPathFromImportPathError, FilePath),
     InvalidModule(RepositoryChildPathModuleError),
     CannotGetModuleForRepositoryFile(RepositoryFileModuleError),
     CannotResolveImports(RepositoryFileResolveImportsError),
     CouldNotCreateRepositoryPath(RepositoryPathFromStringError),
     CouldNotParseModuleMapCollection(ModuleMappingsFromParamStringsError),
+    CouldNotSerializeOutput(serde_json::Error),
+}
+
+impl Display for StatisticsCommandError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StatisticsCommandError::CouldNotParseRepository(_) => write!(f, "invalid repository"),
+            StatisticsCommandError::CouldNotGetFiles(_) => {
+                write!(f, "could not scan repository files")
+            }
+            StatisticsCommandError::InvalidModule(_) => write!(f, "could not determine module"),
+            StatisticsCommandError::CannotGetModuleForRepositoryFile(_) => {
+                write!(f, "could not determine the module of a file")
+            }
+            StatisticsCommandError::CannotResolveImports(_) => write!(f, "could not read imports"),
+            StatisticsCommandError::CouldNotCreateRepositoryPath(_) => {
+                write!(f, "invalid repository path")
+            }
+            StatisticsCommandError::CouldNotParseModuleMapCollection(_) => {
+                write!(f, "invalid --module-mapping")
+            }
+            StatisticsCommandError::CouldNotSerializeOutput(_) => {
+                write!(f, "could not serialize output")
+            }
+        }
+    }
+}
+
+impl std::error::Error for StatisticsCommandError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            StatisticsCommandError::CouldNotParseRepository(e) => Some(e),
+            StatisticsCommandError::CouldNotGetFiles(e) => Some(e),
+            StatisticsCommandError::InvalidModule(e) => Some(e),
+            StatisticsCommandError::CannotGetModuleForRepositoryFile(e) => Some(e),
+            StatisticsCommandError::CannotResolveImports(e) => Some(e),
+            StatisticsCommandError::CouldNotCreateRepositoryPath(e) => Some(e),
+            StatisticsCommandError::CouldNotParseModuleMapCollection(e) => Some(e),
+            StatisticsCommandError::CouldNotSerializeOutput(e) => Some(e),
+        }
+    }
+}
+
+impl From<serde_json::Error> for StatisticsCommandError {
+    fn from(err: serde_json::Error) -> Self {
+        StatisticsCommandError::CouldNotSerializeOutput(err)
+    }
 }
 
 impl From<RepositoryFilesError> for StatisticsCommandError {
@@ -92,6 +149,14 @@ impl From<ModuleMappingsFromParamStringsError> for StatisticsCommandError {
     }
 }
 
+#[derive(Serialize)]
+struct StatisticsJson {
+    modules: usize,
+    external_imports: usize,
+    files_checked: usize,
+    unresolved_imports: usize,
+}
+
 impl StatisticsCommand {
     pub fn run(self) -> Result<(), StatisticsCommandError> {
         let module_mappings: ModuleMappings =
@@ -104,6 +169,7 @@ impl StatisticsCommand {
         );
 
         let file_check_count = AtomicUsize::new(0);
+        let unresolved_import_count = AtomicUsize::new(0);
         let counts: Result<Vec<usize>, StatisticsCommandError> = repository
             .files()
             .map(
@@ -121,22 +187,18 @@ impl StatisticsCommand {
                     let mut count = 0;
                     let current_module = analyzed_file.module()?;
 
+                    unresolved_import_count
+                        .fetch_add(analyzed_file.unresolved_imports()?.len(), Ordering::SeqCst);
+
                     for import_path in analyzed_file.imports()? {
                         let imported_module = match RepositoryChildPath::from_import_path(
                             import_path,
                             &analyzed_file,
-                        )
-                        .map_err(|e| {
-                            StatisticsCommandError::InvalidImport(
-                                e,
-                                analyzed_file.file_path().clone(),
-                            )
-                        }) {
+                        ) {
                             Ok(repository_child_path) => repository_child_path.module()?,
-                            Err(e) => {
-                                eprintln!("{:?}", e);
-                                continue;
-                            }
+                            Err(RepositoryChildPathFromImportPathError::Path(
+                                RepositoryChildPathFromPathError::ImportOutsideRoot(_),
+                            )) => continue,
                         };
 
                         let dependency = Dependency::create(current_module, &imported_module);
@@ -153,9 +215,27 @@ impl StatisticsCommand {
         let total_count = counts?.into_iter().sum::<usize>();
         let total_files_checked = file_check_count.load(Ordering::SeqCst);
 
-        println!("Number of modules: {}", repository.modules().len());
+        let total_unresolved_imports = unresolved_import_count.load(Ordering::SeqCst);
+        let module_count = repository.modules().len();
+
+        if self.format == OutputFormat::Json {
+            print_json(
+                "statistics",
+                StatisticsJson {
+                    modules: module_count,
+                    external_imports: total_count,
+                    files_checked: total_files_checked,
+                    unresolved_imports: total_unresolved_imports,
+                },
+            )?;
+
+            return Ok(());
+        }
+
+        println!("Number of modules: {}", module_count);
         println!("Total imports from outside own modules: {}", total_count);
         println!("Total files checked: {}", total_files_checked);
+        println!("Total unresolved imports: {}", total_unresolved_imports);
 
         Ok(())
     }
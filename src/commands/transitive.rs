@@ -0,0 +1,273 @@
+use belmarsh::{
+    dependency::{
+        analysis::DependencyAnalysis,
+        list::{DependencyList, DependencyListFromRepositoryError},
+    },
+    module::Module,
+    module_mapping::{ModuleMappings, ModuleMappingsFromParamStringsError},
+    repository::{
+        Repository, RepositoryFromStringError, child::RepositoryChildPath,
+        path::RepositoryPathFromStringError,
+    },
+};
+use clap::{Args, ValueEnum};
+use serde::Serialize;
+use std::{fmt::Display, hash::Hash};
+
+use super::output::{OutputFormat, dependencies_from_analysis, print_json};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum TransitiveDirection {
+    Dependencies,
+    Dependants,
+}
+
+impl TransitiveDirection {
+    pub fn id(&self) -> &'static str {
+        match self {
+            TransitiveDirection::Dependencies => "dependencies",
+            TransitiveDirection::Dependants => "dependants",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum TransitiveGranularity {
+    Module,
+    File,
+}
+
+impl TransitiveGranularity {
+    pub fn id(&self) -> &'static str {
+        match self {
+            TransitiveGranularity::Module => "module",
+            TransitiveGranularity::File => "file",
+        }
+    }
+}
+
+#[derive(Args, Debug)]
+#[command(about = "List everything a module or file transitively depends on, or is depended on by")]
+pub struct TransitiveCommand {
+    repository_path: String,
+
+    #[arg(help = "Module name, or a file path relative to the repository with --granularity file")]
+    target: String,
+
+    #[arg(
+        long,
+        help = "Follow dependencies of the target or modules and files depending on it",
+        value_enum,
+        default_value_t = TransitiveDirection::Dependencies
+    )]
+    direction: TransitiveDirection,
+
+    #[arg(
+        long,
+        help = "Query the module graph or the file graph",
+        value_enum,
+        default_value_t = TransitiveGranularity::Module
+    )]
+    granularity: TransitiveGranularity,
+
+    #[arg(long, help = "Only follow this many dependencies away from the target")]
+    depth: Option<usize>,
+
+    #[arg(
+        long,
+        help = "Folders to skip when walking the repository (e.g. node_modules)",
+        value_name = "FOLDER_NAME",
+        default_value = "node_modules"
+    )]
+    skip_folders: Vec<String>,
+
+    #[arg(
+        long,
+        help = "Add a module mapping e.g. --module-mapping @prefix:./path/to/modules",
+        value_name = "ALIAS:PATH"
+    )]
+    module_mapping: Vec<String>,
+
+    #[arg(
+        long,
+        help = "Output format",
+        value_enum,
+        default_value_t = OutputFormat::Text
+    )]
+    format: OutputFormat,
+
+    #[arg(
+        long,
+        help = "Fail when a file cannot be analyzed instead of reporting a partial result"
+    )]
+    strict: bool,
+}
+
+#[derive(Debug)]
+pub enum TransitiveCommandError {
+    CouldNotParseRepository(RepositoryFromStringError),
+    CouldNotGetDependencies(DependencyListFromRepositoryError),
+    InvalidRepositoryPath(RepositoryPathFromStringError),
+    CouldNotParseModuleMapCollection(ModuleMappingsFromParamStringsError),
+    CouldNotSerializeOutput(serde_json::Error),
+}
+
+impl Display for TransitiveCommandError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TransitiveCommandError::CouldNotParseRepository(_) => write!(f, "invalid repository"),
+            TransitiveCommandError::CouldNotGetDependencies(_) => {
+                write!(f, "could not collect dependencies")
+            }
+            TransitiveCommandError::InvalidRepositoryPath(_) => {
+                write!(f, "invalid repository path")
+            }
+            TransitiveCommandError::CouldNotParseModuleMapCollection(_) => {
+                write!(f, "invalid --module-mapping")
+            }
+            TransitiveCommandError::CouldNotSerializeOutput(_) => {
+                write!(f, "could not serialize output")
+            }
+        }
+    }
+}
+
+impl std::error::Error for TransitiveCommandError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            TransitiveCommandError::CouldNotParseRepository(e) => Some(e),
+            TransitiveCommandError::CouldNotGetDependencies(e) => Some(e),
+            TransitiveCommandError::InvalidRepositoryPath(e) => Some(e),
+            TransitiveCommandError::CouldNotParseModuleMapCollection(e) => Some(e),
+            TransitiveCommandError::CouldNotSerializeOutput(e) => Some(e),
+        }
+    }
+}
+
+impl From<serde_json::Error> for TransitiveCommandError {
+    fn from(err: serde_json::Error) -> Self {
+        TransitiveCommandError::CouldNotSerializeOutput(err)
+    }
+}
+
+impl From<RepositoryFromStringError> for TransitiveCommandError {
+    fn from(err: RepositoryFromStringError) -> Self {
+        TransitiveCommandError::CouldNotParseRepository(err)
+    }
+}
+
+impl From<DependencyListFromRepositoryError> for TransitiveCommandError {
+    fn from(value: DependencyListFromRepositoryError) -> Self {
+        TransitiveCommandError::CouldNotGetDependencies(value)
+    }
+}
+
+impl From<RepositoryPathFromStringError> for TransitiveCommandError {
+    fn from(err: RepositoryPathFromStringError) -> Self {
+        TransitiveCommandError::InvalidRepositoryPath(err)
+    }
+}
+
+impl From<ModuleMappingsFromParamStringsError> for TransitiveCommandError {
+    fn from(err: ModuleMappingsFromParamStringsError) -> Self {
+        TransitiveCommandError::CouldNotParseModuleMapCollection(err)
+    }
+}
+
+#[derive(Serialize)]
+struct JsonReached {
+    name: String,
+    distance: usize,
+}
+
+#[derive(Serialize)]
+struct TransitiveJson {
+    target: String,
+    direction: String,
+    granularity: String,
+    depth: Option<usize>,
+    reached: Vec<JsonReached>,
+}
+
+fn reach<T: Clone + Display + Eq + Hash + Send + Sync>(
+    dependencies: DependencyList<T, T>,
+    target: &str,
+    direction: TransitiveDirection,
+    depth: Option<usize>,
+) -> Vec<(String, usize)> {
+    let dependencies = match direction {
+        TransitiveDirection::Dependencies => dependencies,
+        TransitiveDirection::Dependants => dependencies.reversed(),
+    };
+
+    let Some(starting_point) = dependencies
+        .as_ref()
+        .iter()
+        .map(|dependency| &dependency.from)
+        .find(|from| from.to_string() == target)
+    else {
+        return vec![];
+    };
+
+    dependencies
+        .reachable_within(starting_point, depth)
+        .into_iter()
+        .map(|(reached, distance)| (reached.to_string(), distance))
+        .collect()
+}
+
+impl TransitiveCommand {
+    pub fn run(self) -> Result<(), TransitiveCommandError> {
+        let module_mappings: ModuleMappings =
+            ModuleMappings::from_param_strings(self.module_mapping)?;
+
+        let repository: Repository = Repository::new(
+            self.repository_path.try_into()?,
+            module_mappings,
+            self.skip_folders,
+        );
+        let target = self.target.trim_start_matches("./");
+
+        let reached: Vec<(String, usize)> = match self.granularity {
+            TransitiveGranularity::Module => {
+                let dependencies: DependencyList<Module, Module> =
+                    dependencies_from_analysis(DependencyAnalysis::from(repository), self.strict)?;
+
+                reach(dependencies, target, self.direction, self.depth)
+            }
+            TransitiveGranularity::File => {
+                let dependencies: DependencyList<RepositoryChildPath, RepositoryChildPath> =
+                    dependencies_from_analysis(DependencyAnalysis::from(repository), self.strict)?;
+
+                reach(dependencies, target, self.direction, self.depth)
+            }
+        };
+
+        match self.format {
+            OutputFormat::Text => {
+                if reached.is_empty() {
+                    println!("No {} found for {}", self.direction.id(), target);
+                }
+
+                for (name, distance) in reached.iter() {
+                    println!("{} ({})", name, distance);
+                }
+            }
+            OutputFormat::Json => print_json(
+                "transitive",
+                TransitiveJson {
+                    target: target.to_string(),
+                    direction: self.direction.id().to_string(),
+                    granularity: self.granularity.id().to_string(),
+                    depth: self.depth,
+                    reached: reached
+                        .into_iter()
+                        .map(|(name, distance)| JsonReached { name, distance })
+                        .collect(),
+                },
+            )?,
+        }
+
+        Ok(())
+    }
+}
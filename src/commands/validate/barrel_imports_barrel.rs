@@ -1,28 +1,11 @@
-use belmarsh::{
-    dependency::list::{DependencyList, DependencyListFromRepositoryError},
-    repository::{Repository, child::RepositoryChildPath},
-};
+use belmarsh::{dependency::list::DependencyList, repository::child::RepositoryChildPath};
 
 use super::ValidationFailure;
 
-#[derive(Debug)]
-pub enum ValidateBarrelImportsBarrelError {
-    CouldNotGetDependencies(DependencyListFromRepositoryError),
-}
-
-impl From<DependencyListFromRepositoryError> for ValidateBarrelImportsBarrelError {
-    fn from(value: DependencyListFromRepositoryError) -> Self {
-        ValidateBarrelImportsBarrelError::CouldNotGetDependencies(value)
-    }
-}
-
 pub fn validate_barrel_imports_barrel(
-    repository: Repository,
-) -> Result<Vec<ValidationFailure>, ValidateBarrelImportsBarrelError> {
-    let dependencies: DependencyList<RepositoryChildPath, RepositoryChildPath> =
-        repository.try_into()?;
-
-    Ok(dependencies
+    dependencies: &DependencyList<RepositoryChildPath, RepositoryChildPath>,
+) -> Vec<ValidationFailure> {
+    dependencies
         .as_ref()
         .iter()
         .filter_map(|dependency| {
@@ -32,5 +15,5 @@ pub fn validate_barrel_imports_barrel(
                 None
             }
         })
-        .collect())
+        .collect()
 }
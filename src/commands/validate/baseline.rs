@@ -0,0 +1,279 @@
+use std::{
+    collections::BTreeMap,
+    fmt::Display,
+    fs,
+    io::ErrorKind,
+    path::{Path, PathBuf},
+};
+
+use super::{ValidationFailure, check::ValidationCheck};
+
+const BASELINE_HEADER: &str = "# belmarsh validation baseline: one accepted failure per line";
+
+#[derive(Debug)]
+pub enum ValidateBaselineError {
+    CouldNotRead(std::io::Error, PathBuf),
+    CouldNotWrite(std::io::Error, PathBuf),
+}
+
+impl Display for ValidateBaselineError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ValidateBaselineError::CouldNotRead(_, path) => {
+                write!(f, "could not read baseline {}", path.display())
+            }
+            ValidateBaselineError::CouldNotWrite(_, path) => {
+                write!(f, "could not write baseline {}", path.display())
+            }
+        }
+    }
+}
+
+impl std::error::Error for ValidateBaselineError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ValidateBaselineError::CouldNotRead(e, _) => Some(e),
+            ValidateBaselineError::CouldNotWrite(e, _) => Some(e),
+        }
+    }
+}
+
+pub fn baseline_entry(check: ValidationCheck, failure: &ValidationFailure) -> String {
+    format!("{}\t{}", check.id(), failure.subject())
+}
+
+fn is_entry_for(entry: &str, checks: &[ValidationCheck]) -> bool {
+    let check_id = entry.split('\t').next().unwrap_or_default();
+
+    checks.iter().any(|check| check.id() == check_id)
+}
+
+#[derive(Debug, Default)]
+pub struct Baseline(BTreeMap<String, usize>);
+
+impl Baseline {
+    pub fn from_failures(results: &[(ValidationCheck, Vec<ValidationFailure>)]) -> Self {
+        let mut entries: BTreeMap<String, usize> = BTreeMap::new();
+
+        for (check, failures) in results.iter() {
+            for failure in failures.iter() {
+                *entries.entry(baseline_entry(*check, failure)).or_default() += 1;
+            }
+        }
+
+        Baseline(entries)
+    }
+
+    pub fn from_file(path: &Path) -> Result<Option<Self>, ValidateBaselineError> {
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(ValidateBaselineError::CouldNotRead(e, path.to_path_buf())),
+        };
+
+        let mut entries: BTreeMap<String, usize> = BTreeMap::new();
+
+        for line in contents
+            .lines()
+            .filter(|line| !line.trim().is_empty() && !line.starts_with('#'))
+        {
+            *entries.entry(line.to_string()).or_default() += 1;
+        }
+
+        Ok(Some(Baseline(entries)))
+    }
+
+    pub fn write(&self, path: &Path) -> Result<(), ValidateBaselineError> {
+        let mut contents = format!("{}\n", BASELINE_HEADER);
+
+        for (entry, count) in self.0.iter() {
+            for _ in 0..*count {
+                contents.push_str(entry);
+                contents.push('\n');
+            }
+        }
+
+        fs::write(path, contents)
+            .map_err(|e| ValidateBaselineError::CouldNotWrite(e, path.to_path_buf()))
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.values().sum()
+    }
+
+    pub fn take(&mut self, entry: &str) -> bool {
+        match self.0.get_mut(entry) {
+            Some(count) if *count > 0 => {
+                *count -= 1;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Keeps the entries of `previous` belonging to checks other than
+    /// `checks`, so rewriting the baseline after running only some checks
+    /// leaves the entries of the others untouched.
+    pub fn with_entries_of_other_checks(
+        mut self,
+        previous: &Baseline,
+        checks: &[ValidationCheck],
+    ) -> Self {
+        for (entry, count) in previous.0.iter() {
+            if !is_entry_for(entry, checks) {
+                *self.0.entry(entry.clone()).or_default() += count;
+            }
+        }
+
+        self
+    }
+
+    /// Entries of `checks` that no failure took. Entries of checks that did
+    /// not run are not known to be fixed and are left out.
+    pub fn remaining(&self, checks: &[ValidationCheck]) -> Vec<&str> {
+        self.0
+            .iter()
+            .filter(|(entry, _)| is_entry_for(entry, checks))
+            .flat_map(|(entry, count)| (0..*count).map(move |_| entry.as_str()))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use belmarsh::{
+        dependency::{chain::DependencyChain, cycle::CycleDetector},
+        module::Module,
+    };
+
+    use super::*;
+
+    fn fan_out(count: usize) -> ValidationFailure {
+        ValidationFailure::ModuleFanOutExceeded(Module::from("checkout".to_string()), count, 10)
+    }
+
+    fn cycles(edges: &[(&str, &str)]) -> Vec<ValidationFailure> {
+        let mut grouped_dependencies: HashMap<String, Vec<String>> = HashMap::new();
+
+        for (from, to) in edges {
+            grouped_dependencies
+                .entry(from.to_string())
+                .or_default()
+                .push(to.to_string());
+        }
+
+        CycleDetector::new(grouped_dependencies)
+            .find_cycles()
+            .into_iter()
+            .map(ValidationFailure::CircularDependency)
+            .collect()
+    }
+
+    fn entries(check: ValidationCheck, failures: &[ValidationFailure]) -> Vec<String> {
+        failures
+            .iter()
+            .map(|failure| baseline_entry(check, failure))
+            .collect()
+    }
+
+    #[test]
+    fn fan_limit_entries_ignore_the_count() {
+        assert_eq!(
+            baseline_entry(ValidationCheck::FanLimits, &fan_out(12)),
+            baseline_entry(ValidationCheck::FanLimits, &fan_out(11))
+        );
+    }
+
+    #[test]
+    fn fan_limit_entries_keep_the_direction() {
+        let fan_in =
+            ValidationFailure::ModuleFanInExceeded(Module::from("checkout".to_string()), 12, 10);
+
+        assert_ne!(
+            baseline_entry(ValidationCheck::FanLimits, &fan_out(12)),
+            baseline_entry(ValidationCheck::FanLimits, &fan_in)
+        );
+    }
+
+    #[test]
+    fn baseline_accepts_a_reduced_fan_limit_excess() {
+        let mut baseline =
+            Baseline::from_failures(&[(ValidationCheck::FanLimits, vec![fan_out(12)])]);
+
+        assert!(baseline.take(&baseline_entry(ValidationCheck::FanLimits, &fan_out(11))));
+        assert!(baseline.remaining(&[ValidationCheck::FanLimits]).is_empty());
+    }
+
+    #[test]
+    fn only_entries_of_checks_that_ran_are_fixed() {
+        let baseline = Baseline::from_failures(&[
+            (ValidationCheck::FanLimits, vec![fan_out(12)]),
+            (
+                ValidationCheck::CircularModules,
+                cycles(&[("a", "b"), ("b", "a")]),
+            ),
+        ]);
+
+        assert_eq!(
+            baseline.remaining(&[ValidationCheck::CircularModules]),
+            vec!["circular-modules\ta > b > a"]
+        );
+    }
+
+    #[test]
+    fn rewriting_keeps_the_entries_of_checks_that_did_not_run() {
+        let previous = Baseline::from_failures(&[
+            (ValidationCheck::FanLimits, vec![fan_out(12)]),
+            (
+                ValidationCheck::CircularModules,
+                cycles(&[("a", "b"), ("b", "a")]),
+            ),
+        ]);
+
+        let baseline = Baseline::from_failures(&[(ValidationCheck::CircularModules, vec![])])
+            .with_entries_of_other_checks(&previous, &[ValidationCheck::CircularModules]);
+
+        assert_eq!(
+            baseline.remaining(&[ValidationCheck::FanLimits, ValidationCheck::CircularModules]),
+            vec![baseline_entry(ValidationCheck::FanLimits, &fan_out(12))]
+        );
+    }
+
+    #[test]
+    fn circular_entries_start_at_the_smallest_member() {
+        let rotated = ValidationFailure::CircularDependency(DependencyChain(vec![
+            "b".to_string(),
+            "c".to_string(),
+            "a".to_string(),
+            "b".to_string(),
+        ]));
+
+        assert_eq!(
+            baseline_entry(ValidationCheck::CircularModules, &rotated),
+            "circular-modules\ta > b > c > a"
+        );
+    }
+
+    #[test]
+    fn circular_entries_are_stable_between_runs() {
+        let edges = [
+            ("a", "b"),
+            ("b", "c"),
+            ("c", "a"),
+            ("c", "d"),
+            ("d", "b"),
+            ("e", "a"),
+            ("b", "a"),
+        ];
+        let first = entries(ValidationCheck::CircularModules, &cycles(&edges));
+
+        for _ in 0..20 {
+            assert_eq!(
+                entries(ValidationCheck::CircularModules, &cycles(&edges)),
+                first
+            );
+        }
+    }
+}
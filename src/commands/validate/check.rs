@@ -0,0 +1,75 @@
+use std::fmt::Display;
+
+use clap::ValueEnum;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+impl Severity {
+    pub fn for_check(check: ValidationCheck, warnings: &[ValidationCheck]) -> Self {
+        if warnings.contains(&check) {
+            Severity::Warning
+        } else {
+            Severity::Error
+        }
+    }
+}
+
+impl Display for Severity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Severity::Error => write!(f, "error"),
+            Severity::Warning => write!(f, "warning"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ValueEnum)]
+pub enum ValidationCheck {
+    CircularModules,
+    CircularFiles,
+    ExternalBarrelImports,
+    BarrelImportsBarrel,
+    OwnBarrelImports,
+    UnresolvedImports,
+    UnusedBarrelExports,
+    UnreachableFiles,
+    FanLimits,
+}
+
+impl ValidationCheck {
+    pub fn id(&self) -> &'static str {
+        match self {
+            ValidationCheck::CircularModules => "circular-modules",
+            ValidationCheck::CircularFiles => "circular-files",
+            ValidationCheck::ExternalBarrelImports => "external-barrel-imports",
+            ValidationCheck::BarrelImportsBarrel => "barrel-imports-barrel",
+            ValidationCheck::OwnBarrelImports => "own-barrel-imports",
+            ValidationCheck::UnresolvedImports => "unresolved-imports",
+            ValidationCheck::UnusedBarrelExports => "unused-barrel-exports",
+            ValidationCheck::UnreachableFiles => "unreachable-files",
+            ValidationCheck::FanLimits => "fan-limits",
+        }
+    }
+}
+
+impl Display for ValidationCheck {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            ValidationCheck::CircularModules => "circular module",
+            ValidationCheck::CircularFiles => "circular file",
+            ValidationCheck::ExternalBarrelImports => "external barrel import",
+            ValidationCheck::BarrelImportsBarrel => "barrel imports barrel",
+            ValidationCheck::OwnBarrelImports => "own barrel import",
+            ValidationCheck::UnresolvedImports => "unresolved import",
+            ValidationCheck::UnusedBarrelExports => "unused barrel export",
+            ValidationCheck::UnreachableFiles => "unreachable file",
+            ValidationCheck::FanLimits => "fan-in/fan-out limit",
+        };
+
+        write!(f, "{}", name)
+    }
+}
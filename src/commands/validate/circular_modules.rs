@@ -1,47 +1,41 @@
-use std::collections::HashMap;
-
 use belmarsh::{
-    dependency::{
-        cycle::CycleDetector,
-        list::{DependencyList, DependencyListFromRepositoryError},
-    },
+    dependency::{cycle::CycleDetector, list::DependencyList},
     module::Module,
-    repository::Repository,
+    repository::child::RepositoryChildPath,
 };
 
-use super::ValidationFailure;
+use super::{CycleReport, ValidationFailure, report::SuggestedFix};
 
-#[derive(Debug)]
-pub enum ValidateCircularModuleError {
-    CouldNotGetDependencies(DependencyListFromRepositoryError),
-}
+pub fn validate_circular_modules(
+    file_dependencies: &DependencyList<RepositoryChildPath, RepositoryChildPath>,
+    cycles: CycleReport,
+) -> Vec<ValidationFailure> {
+    let dependencies: DependencyList<Module, Module> = file_dependencies.to_module_dependencies();
 
-impl From<DependencyListFromRepositoryError> for ValidateCircularModuleError {
-    fn from(value: DependencyListFromRepositoryError) -> Self {
-        ValidateCircularModuleError::CouldNotGetDependencies(value)
+    match cycles {
+        CycleReport::Chains => CycleDetector::new(dependencies.group_by_from_string())
+            .find_cycles()
+            .into_iter()
+            .map(ValidationFailure::CircularDependency)
+            .collect(),
+        CycleReport::Components => dependencies
+            .to_tangled_components()
+            .into_iter()
+            .map(ValidationFailure::TangledComponent)
+            .collect(),
     }
 }
 
-pub fn validate_circular_modules(
-    repository: Repository,
-) -> Result<Vec<ValidationFailure>, ValidateCircularModuleError> {
-    let dependencies: DependencyList<Module, Module> = repository.try_into()?;
-    let grouped_by_from = dependencies.group_by_from();
-    let string_grouped_dependencies: HashMap<String, Vec<String>> = grouped_by_from
+pub fn suggest_cycle_breaks(
+    file_dependencies: &DependencyList<RepositoryChildPath, RepositoryChildPath>,
+) -> Vec<SuggestedFix> {
+    file_dependencies
+        .to_module_dependencies()
+        .to_feedback_arc_set(&file_dependencies.count_module_imports())
         .into_iter()
-        .map(|(from, to_list)| {
-            (
-                from.to_string(),
-                to_list.into_iter().map(|to| to.to_string()).collect(),
-            )
+        .map(|(dependency, imports)| SuggestedFix {
+            dependency,
+            imports,
         })
-        .collect();
-
-    let detector = CycleDetector::new(string_grouped_dependencies);
-
-    Ok(detector
-        .find_cycles()
-        .into_iter()
-        .map(|chain| ValidationFailure::CircularDependency(chain))
-        .collect())
+        .collect()
 }
@@ -1,28 +1,11 @@
-use belmarsh::{
-    dependency::list::{DependencyList, DependencyListFromRepositoryError},
-    repository::{Repository, child::RepositoryChildPath},
-};
+use belmarsh::{dependency::list::DependencyList, repository::child::RepositoryChildPath};
 
 use super::ValidationFailure;
 
-#[derive(Debug)]
-pub enum ValidateExternalBarrelImportsError {
-    CouldNotGetDependencies(DependencyListFromRepositoryError),
-}
-
-impl From<DependencyListFromRepositoryError> for ValidateExternalBarrelImportsError {
-    fn from(value: DependencyListFromRepositoryError) -> Self {
-        ValidateExternalBarrelImportsError::CouldNotGetDependencies(value)
-    }
-}
-
 pub fn validate_external_barrel_imports(
-    repository: Repository,
-) -> Result<Vec<ValidationFailure>, ValidateExternalBarrelImportsError> {
-    let dependencies: DependencyList<RepositoryChildPath, RepositoryChildPath> =
-        repository.try_into()?;
-
-    Ok(dependencies
+    dependencies: &DependencyList<RepositoryChildPath, RepositoryChildPath>,
+) -> Vec<ValidationFailure> {
+    dependencies
         .as_ref()
         .iter()
         .filter(|dependency| !dependency.is_internal())
@@ -33,5 +16,5 @@ pub fn validate_external_barrel_imports(
                 Some(ValidationFailure::ExternalBarrelImport(dependency.clone()))
             }
         })
-        .collect())
+        .collect()
 }
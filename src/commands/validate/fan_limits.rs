@@ -0,0 +1,132 @@
+use std::collections::HashSet;
+
+use belmarsh::{
+    dependency::{Dependency, list::DependencyList},
+    module::Module,
+    repository::child::RepositoryChildPath,
+};
+
+use super::ValidationFailure;
+
+#[derive(Debug, Default)]
+pub struct FanLimits {
+    pub max_module_fan_out: Option<usize>,
+    pub max_module_fan_in: Option<usize>,
+    pub max_file_fan_out: Option<usize>,
+    pub max_file_fan_in: Option<usize>,
+}
+
+impl FanLimits {
+    pub fn is_empty(&self) -> bool {
+        self.max_module_fan_out.is_none()
+            && self.max_module_fan_in.is_none()
+            && self.max_file_fan_out.is_none()
+            && self.max_file_fan_in.is_none()
+    }
+}
+
+fn excess(failure: &ValidationFailure) -> usize {
+    match failure {
+        ValidationFailure::ModuleFanOutExceeded(_, count, limit)
+        | ValidationFailure::ModuleFanInExceeded(_, count, limit)
+        | ValidationFailure::FileFanOutExceeded(_, count, limit)
+        | ValidationFailure::FileFanInExceeded(_, count, limit) => count - limit,
+        _ => 0,
+    }
+}
+
+pub fn validate_fan_limits(
+    file_dependencies: &DependencyList<RepositoryChildPath, RepositoryChildPath>,
+    limits: &FanLimits,
+) -> Vec<ValidationFailure> {
+    let mut failures: Vec<ValidationFailure> = vec![];
+
+    if limits.max_module_fan_out.is_some() || limits.max_module_fan_in.is_some() {
+        let dependencies: DependencyList<Module, Module> =
+            file_dependencies.to_module_dependencies();
+
+        if let Some(limit) = limits.max_module_fan_out {
+            failures.extend(
+                dependencies
+                    .group_by_from()
+                    .into_iter()
+                    .filter(|(_, to_list)| to_list.len() > limit)
+                    .map(|(module, to_list)| {
+                        ValidationFailure::ModuleFanOutExceeded(module, to_list.len(), limit)
+                    }),
+            );
+        }
+
+        if let Some(limit) = limits.max_module_fan_in {
+            failures.extend(
+                dependencies
+                    .group_by_to()
+                    .into_iter()
+                    .filter(|(_, from_list)| from_list.len() > limit)
+                    .map(|(module, from_list)| {
+                        ValidationFailure::ModuleFanInExceeded(module, from_list.len(), limit)
+                    }),
+            );
+        }
+    }
+
+    if let Some(limit) = limits.max_file_fan_out {
+        let dependencies: DependencyList<RepositoryChildPath, Module> = file_dependencies
+            .as_ref()
+            .iter()
+            .filter_map(
+                |dependency| match (dependency.from.module(), dependency.to.module()) {
+                    (Ok(from_module), Ok(to_module)) if from_module != to_module => {
+                        Some(Dependency::create(dependency.from.clone(), to_module))
+                    }
+                    _ => None,
+                },
+            )
+            .collect::<HashSet<Dependency<RepositoryChildPath, Module>>>()
+            .into();
+
+        failures.extend(
+            dependencies
+                .group_by_from()
+                .into_iter()
+                .filter(|(_, to_list)| to_list.len() > limit)
+                .map(|(file, to_list)| {
+                    ValidationFailure::FileFanOutExceeded(file, to_list.len(), limit)
+                }),
+        );
+    }
+
+    if let Some(limit) = limits.max_file_fan_in {
+        let dependencies: DependencyList<Module, RepositoryChildPath> = file_dependencies
+            .as_ref()
+            .iter()
+            .filter(|dependency| !dependency.is_internal())
+            .filter_map(|dependency| {
+                dependency
+                    .from
+                    .module()
+                    .ok()
+                    .map(|from_module| Dependency::create(from_module, dependency.to.clone()))
+            })
+            .collect::<HashSet<Dependency<Module, RepositoryChildPath>>>()
+            .into();
+
+        failures.extend(
+            dependencies
+                .group_by_to()
+                .into_iter()
+                .filter(|(_, from_list)| from_list.len() > limit)
+                .map(|(file, from_list)| {
+                    ValidationFailure::FileFanInExceeded(file, from_list.len(), limit)
+                }),
+        );
+    }
+
+    failures.sort_by(|a, b| {
+        excess(b)
+            .cmp(&excess(a))
+            .then_with(|| a.to_string().cmp(&b.to_string()))
+    });
+
+    failures
+}
@@ -1,26 +1,72 @@
-use std::fmt::Display;
+use std::{collections::HashMap, fmt::Display, path::PathBuf};
 
 use belmarsh::{
-    dependency::{Dependency, chain::DependencyChain},
+    dependency::{
+        Dependency, analysis::DependencyAnalysis, chain::DependencyChain,
+        component::DependencyComponent,
+    },
+    git::GitError,
+    import_path::UnresolvedImport,
+    module::Module,
     module_mapping::{ModuleMappings, ModuleMappingsFromParamStringsError},
     repository::{Repository, child::RepositoryChildPath, path::RepositoryPathFromStringError},
 };
-use clap::{Args, command};
+use clap::{ArgGroup, Args, ValueEnum, command};
+
+use crate::commands::output::print_diagnostics;
 
 pub mod barrel_imports_barrel;
+pub mod baseline;
+pub mod check;
 pub mod circular_files;
 pub mod circular_modules;
 pub mod external_barrel_imports;
-
-use barrel_imports_barrel::{ValidateBarrelImportsBarrelError, validate_barrel_imports_barrel};
-use circular_files::{ValidateCircularFilesError, validate_circular_files};
-use circular_modules::{ValidateCircularModuleError, validate_circular_modules};
-use external_barrel_imports::{
-    ValidateExternalBarrelImportsError, validate_external_barrel_imports,
+pub mod fan_limits;
+pub mod own_barrel_imports;
+pub mod report;
+pub mod since;
+pub mod suppression;
+pub mod unreachable_files;
+pub mod unresolved_imports;
+pub mod unused_barrel_exports;
+
+use barrel_imports_barrel::validate_barrel_imports_barrel;
+use baseline::{Baseline, ValidateBaselineError, baseline_entry};
+use check::{Severity, ValidationCheck};
+use circular_files::validate_circular_files;
+use circular_modules::{suggest_cycle_breaks, validate_circular_modules};
+use external_barrel_imports::validate_external_barrel_imports;
+use fan_limits::{FanLimits, validate_fan_limits};
+use own_barrel_imports::validate_own_barrel_imports;
+use report::{
+    CheckReport, ReportedFailure, ValidationReport, github, gitlab, json, junit, location::locate,
+    print_text, sarif,
 };
+use since::ChangedFiles;
+use suppression::{RepositorySuppressions, ValidateSuppressionsError};
+use unreachable_files::{ValidateUnreachableFilesError, validate_unreachable_files};
+use unresolved_imports::{ValidateUnresolvedImportsError, validate_unresolved_imports};
+use unused_barrel_exports::{ValidateUnusedBarrelExportsError, validate_unused_barrel_exports};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum ValidateOutputFormat {
+    Text,
+    Json,
+    Sarif,
+    Junit,
+    Github,
+    Gitlab,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum CycleReport {
+    Chains,
+    Components,
+}
 
 #[derive(Args, Debug)]
 #[command(about = "Validate")]
+#[command(group(ArgGroup::new("fan_limit").multiple(true)))]
 pub struct ValidateCommand {
     repository_path: String,
 
@@ -30,12 +76,131 @@ pub struct ValidateCommand {
     #[arg(long, help = "Run circular file validation")]
     circular_files: bool,
 
+    #[arg(
+        long,
+        help = "How circular validations report cycles: every chain found or each tangled component once",
+        value_enum,
+        default_value_t = CycleReport::Components
+    )]
+    cycles: CycleReport,
+
+    #[arg(
+        long,
+        help = "Suggest module dependencies to remove to break every module cycle, fewest imports first"
+    )]
+    suggest_fixes: bool,
+
     #[arg(long, help = "Run external barrel validation")]
     external_barrel_imports: bool,
 
     #[arg(long, help = "Run barrel imports barrel validation")]
     barrel_imports_barrel: bool,
 
+    #[arg(long, help = "Run own barrel import validation")]
+    own_barrel_imports: bool,
+
+    #[arg(long, help = "Run unresolved import validation")]
+    unresolved_imports: bool,
+
+    #[arg(long, help = "Run unused barrel export validation")]
+    unused_barrel_exports: bool,
+
+    #[arg(
+        long,
+        help = "Run unreachable file validation",
+        requires = "entry_point"
+    )]
+    unreachable_files: bool,
+
+    #[arg(
+        long,
+        help = "Add an entry point glob for unreachable file validation e.g. --entry-point '*.spec.ts'",
+        value_name = "GLOB"
+    )]
+    entry_point: Vec<String>,
+
+    #[arg(
+        long,
+        help = "Run fan-in/fan-out limit validation",
+        requires = "fan_limit"
+    )]
+    fan_limits: bool,
+
+    #[arg(
+        long,
+        help = "Maximum number of modules a module may depend on",
+        value_name = "COUNT",
+        group = "fan_limit"
+    )]
+    max_module_fan_out: Option<usize>,
+
+    #[arg(
+        long,
+        help = "Maximum number of modules that may depend on a module",
+        value_name = "COUNT",
+        group = "fan_limit"
+    )]
+    max_module_fan_in: Option<usize>,
+
+    #[arg(
+        long,
+        help = "Maximum number of other modules a file may import from",
+        value_name = "COUNT",
+        group = "fan_limit"
+    )]
+    max_file_fan_out: Option<usize>,
+
+    #[arg(
+        long,
+        help = "Maximum number of other modules that may import a file",
+        value_name = "COUNT",
+        group = "fan_limit"
+    )]
+    max_file_fan_in: Option<usize>,
+
+    #[arg(
+        long,
+        help = "Report failures of this check as warnings that do not fail validation",
+        value_name = "CHECK"
+    )]
+    warn: Vec<ValidationCheck>,
+
+    #[arg(
+        long,
+        help = "Only report failures not recorded in this baseline file, creating it if missing",
+        value_name = "FILE"
+    )]
+    baseline: Option<PathBuf>,
+
+    #[arg(
+        long,
+        help = "Rewrite the baseline file with the current failures",
+        requires = "baseline"
+    )]
+    update_baseline: bool,
+
+    #[arg(
+        long,
+        help = "Only report failures involving files changed in the local git repository since this revision",
+        value_name = "REV",
+        conflicts_with = "update_baseline"
+    )]
+    since: Option<String>,
+
+    #[arg(
+        long,
+        help = "Output format",
+        value_enum,
+        default_value_t = ValidateOutputFormat::Text
+    )]
+    format: ValidateOutputFormat,
+
+    #[arg(
+        long,
+        help = "Report files that cannot be analyzed as errors instead of warnings"
+    )]
+    strict: bool,
+
     #[arg(
         long,
         help = "Add a module mapping e.g. --module-mapping @prefix:./path/to/modules",
@@ -54,35 +219,98 @@ pub struct ValidateCommand {
 
 #[derive(Debug)]
 pub enum ValidateCommandError {
-    CircularModuleError(ValidateCircularModuleError),
-    CircularFileError(ValidateCircularFilesError),
-    ExternalBarrelImportsError(ValidateExternalBarrelImportsError),
-    BarrelImportsBarrelError(ValidateBarrelImportsBarrelError),
+    UnresolvedImportsError(ValidateUnresolvedImportsError),
+    UnreachableFilesError(ValidateUnreachableFilesError),
+    UnusedBarrelExportsError(ValidateUnusedBarrelExportsError),
+    BaselineError(ValidateBaselineError),
+    SuppressionsError(ValidateSuppressionsError),
+    CouldNotSerializeOutput(serde_json::Error),
     CouldNotCreateRepositoryPath(RepositoryPathFromStringError),
     CouldNotParseModuleMapCollection(ModuleMappingsFromParamStringsError),
+    CouldNotGetGitChanges(GitError),
 }
 
-impl From<ValidateBarrelImportsBarrelError> for ValidateCommandError {
-    fn from(value: ValidateBarrelImportsBarrelError) -> Self {
-        ValidateCommandError::BarrelImportsBarrelError(value)
+impl Display for ValidateCommandError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ValidateCommandError::UnresolvedImportsError(_) => {
+                write!(f, "could not run unresolved import validation")
+            }
+            ValidateCommandError::UnreachableFilesError(_) => {
+                write!(f, "could not run unreachable file validation")
+            }
+            ValidateCommandError::UnusedBarrelExportsError(_) => {
+                write!(f, "could not run unused barrel export validation")
+            }
+            ValidateCommandError::BaselineError(_) => write!(f, "could not apply the baseline"),
+            ValidateCommandError::SuppressionsError(_) => {
+                write!(f, "could not read suppression comments")
+            }
+            ValidateCommandError::CouldNotSerializeOutput(_) => {
+                write!(f, "could not serialize output")
+            }
+            ValidateCommandError::CouldNotCreateRepositoryPath(_) => {
+                write!(f, "invalid repository path")
+            }
+            ValidateCommandError::CouldNotParseModuleMapCollection(_) => {
+                write!(f, "invalid --module-mapping")
+            }
+            ValidateCommandError::CouldNotGetGitChanges(_) => {
+                write!(f, "could not list files changed in git")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ValidateCommandError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ValidateCommandError::UnresolvedImportsError(e) => Some(e),
+            ValidateCommandError::UnreachableFilesError(e) => Some(e),
+            ValidateCommandError::UnusedBarrelExportsError(e) => Some(e),
+            ValidateCommandError::BaselineError(e) => Some(e),
+            ValidateCommandError::SuppressionsError(e) => Some(e),
+            ValidateCommandError::CouldNotSerializeOutput(e) => Some(e),
+            ValidateCommandError::CouldNotCreateRepositoryPath(e) => Some(e),
+            ValidateCommandError::CouldNotParseModuleMapCollection(e) => Some(e),
+            ValidateCommandError::CouldNotGetGitChanges(e) => Some(e),
+        }
     }
 }
 
-impl From<ValidateExternalBarrelImportsError> for ValidateCommandError {
-    fn from(value: ValidateExternalBarrelImportsError) -> Self {
-        ValidateCommandError::ExternalBarrelImportsError(value)
+impl From<serde_json::Error> for ValidateCommandError {
+    fn from(value: serde_json::Error) -> Self {
+        ValidateCommandError::CouldNotSerializeOutput(value)
     }
 }
 
-impl From<ValidateCircularFilesError> for ValidateCommandError {
-    fn from(value: ValidateCircularFilesError) -> Self {
-        ValidateCommandError::CircularFileError(value)
+impl From<ValidateSuppressionsError> for ValidateCommandError {
+    fn from(value: ValidateSuppressionsError) -> Self {
+        ValidateCommandError::SuppressionsError(value)
     }
 }
 
-impl From<ValidateCircularModuleError> for ValidateCommandError {
-    fn from(value: ValidateCircularModuleError) -> Self {
-        ValidateCommandError::CircularModuleError(value)
+impl From<ValidateBaselineError> for ValidateCommandError {
+    fn from(value: ValidateBaselineError) -> Self {
+        ValidateCommandError::BaselineError(value)
+    }
+}
+
+impl From<ValidateUnusedBarrelExportsError> for ValidateCommandError {
+    fn from(value: ValidateUnusedBarrelExportsError) -> Self {
+        ValidateCommandError::UnusedBarrelExportsError(value)
+    }
+}
+
+impl From<ValidateUnreachableFilesError> for ValidateCommandError {
+    fn from(value: ValidateUnreachableFilesError) -> Self {
+        ValidateCommandError::UnreachableFilesError(value)
+    }
+}
+
+impl From<ValidateUnresolvedImportsError> for ValidateCommandError {
+    fn from(value: ValidateUnresolvedImportsError) -> Self {
+        ValidateCommandError::UnresolvedImportsError(value)
     }
 }
 
@@ -92,6 +320,12 @@ impl From<RepositoryPathFromStringError> for ValidateCommandError {
     }
 }
 
+impl From<GitError> for ValidateCommandError {
+    fn from(err: GitError) -> Self {
+        ValidateCommandError::CouldNotGetGitChanges(err)
+    }
+}
+
 impl From<ModuleMappingsFromParamStringsError> for ValidateCommandError {
     fn from(err: ModuleMappingsFromParamStringsError) -> Self {
         ValidateCommandError::CouldNotParseModuleMapCollection(err)
@@ -100,8 +334,61 @@ impl From<ModuleMappingsFromParamStringsError> for ValidateCommandError {
 
 pub enum ValidationFailure {
     CircularDependency(DependencyChain),
+    TangledComponent(DependencyComponent),
     ExternalBarrelImport(Dependency<RepositoryChildPath, RepositoryChildPath>),
     BarrelImportsBarrel(Dependency<RepositoryChildPath, RepositoryChildPath>),
+    OwnBarrelImport(Dependency<RepositoryChildPath, RepositoryChildPath>),
+    UnresolvedImport(RepositoryChildPath, UnresolvedImport),
+    UnreachableFile(Module, RepositoryChildPath),
+    UnusedBarrelExport(RepositoryChildPath, usize, String),
+    ModuleFanOutExceeded(Module, usize, usize),
+    ModuleFanInExceeded(Module, usize, usize),
+    FileFanOutExceeded(RepositoryChildPath, usize, usize),
+    FileFanInExceeded(RepositoryChildPath, usize, usize),
+}
+
+impl ValidationFailure {
+    /// Identifies the failure across runs, counts that may change without
+    /// fixing it are left out.
+    pub fn subject(&self) -> String {
+        match self {
+            ValidationFailure::CircularDependency(chain) => chain.canonical().to_string(),
+            ValidationFailure::TangledComponent(component) => component.to_string(),
+            ValidationFailure::ExternalBarrelImport(dependency)
+            | ValidationFailure::BarrelImportsBarrel(dependency)
+            | ValidationFailure::OwnBarrelImport(dependency) => dependency.to_string(),
+            ValidationFailure::UnresolvedImport(file, unresolved_import) => {
+                format!("{} > '{}'", file, unresolved_import.specifier())
+            }
+            ValidationFailure::UnreachableFile(_, file) => file.to_string(),
+            ValidationFailure::UnusedBarrelExport(barrel, _, name) => {
+                format!("{} > {}", barrel, name)
+            }
+            ValidationFailure::ModuleFanOutExceeded(module, _, _) => {
+                format!("{} (fan-out)", module)
+            }
+            ValidationFailure::ModuleFanInExceeded(module, _, _) => format!("{} (fan-in)", module),
+            ValidationFailure::FileFanOutExceeded(file, _, _) => format!("{} (fan-out)", file),
+            ValidationFailure::FileFanInExceeded(file, _, _) => format!("{} (fan-in)", file),
+        }
+    }
+
+    pub fn kind(&self) -> &'static str {
+        match self {
+            ValidationFailure::CircularDependency(_) => "circular-dependency",
+            ValidationFailure::TangledComponent(_) => "tangled-component",
+            ValidationFailure::ExternalBarrelImport(_) => "external-barrel-import",
+            ValidationFailure::BarrelImportsBarrel(_) => "barrel-imports-barrel",
+            ValidationFailure::OwnBarrelImport(_) => "own-barrel-import",
+            ValidationFailure::UnresolvedImport(_, _) => "unresolved-import",
+            ValidationFailure::UnreachableFile(_, _) => "unreachable-file",
+            ValidationFailure::UnusedBarrelExport(_, _, _) => "unused-barrel-export",
+            ValidationFailure::ModuleFanOutExceeded(_, _, _) => "module-fan-out-exceeded",
+            ValidationFailure::ModuleFanInExceeded(_, _, _) => "module-fan-in-exceeded",
+            ValidationFailure::FileFanOutExceeded(_, _, _) => "file-fan-out-exceeded",
+            ValidationFailure::FileFanInExceeded(_, _, _) => "file-fan-in-exceeded",
+        }
+    }
 }
 
 impl Display for ValidationFailure {
@@ -110,87 +397,346 @@ impl Display for ValidationFailure {
             ValidationFailure::CircularDependency(chain) => {
                 write!(f, "Circular dependency: {}", chain)
             }
+            ValidationFailure::TangledComponent(component) => {
+                write!(
+                    f,
+                    "Tangled component of {}: {}; internal edges: {}",
+                    component.members().len(),
+                    component,
+                    component
+                        .edges()
+                        .iter()
+                        .map(|(from, to)| format!("{} > {}", from, to))
+                        .collect::<Vec<String>>()
+                        .join(", ")
+                )
+            }
             ValidationFailure::ExternalBarrelImport(dependency) => {
                 write!(
                     f,
-                    "External import does not use a barrel file: {}",
-                    dependency
+                    "External import does not use a barrel file: {} > {}",
+                    dependency.located_from(),
+                    dependency.to
                 )
             }
             ValidationFailure::BarrelImportsBarrel(dependency) => {
-                write!(f, "Barrel file imports another barrel file: {}", dependency)
+                write!(
+                    f,
+                    "Barrel file imports another barrel file: {} > {}",
+                    dependency.located_from(),
+                    dependency.to
+                )
+            }
+            ValidationFailure::OwnBarrelImport(dependency) => {
+                write!(
+                    f,
+                    "Internal import uses its own module barrel file: {} > {}",
+                    dependency.located_from(),
+                    dependency.to
+                )
+            }
+            ValidationFailure::UnresolvedImport(file, unresolved_import) => {
+                write!(
+                    f,
+                    "Import could not be resolved: {}:{} > '{}'",
+                    file,
+                    unresolved_import.location(),
+                    unresolved_import.specifier()
+                )
+            }
+            ValidationFailure::UnreachableFile(module, file) => {
+                write!(
+                    f,
+                    "File is not reachable from any entry point: {} > {}",
+                    module, file
+                )
+            }
+            ValidationFailure::UnusedBarrelExport(barrel, line, name) => {
+                write!(
+                    f,
+                    "Barrel export is not imported from outside its module: {}:{} > {}",
+                    barrel, line, name
+                )
+            }
+            ValidationFailure::ModuleFanOutExceeded(module, count, limit) => {
+                write!(
+                    f,
+                    "Module depends on too many modules: {} depends on {} (limit {})",
+                    module, count, limit
+                )
+            }
+            ValidationFailure::ModuleFanInExceeded(module, count, limit) => {
+                write!(
+                    f,
+                    "Module is depended on by too many modules: {} is used by {} (limit {})",
+                    module, count, limit
+                )
+            }
+            ValidationFailure::FileFanOutExceeded(file, count, limit) => {
+                write!(
+                    f,
+                    "File imports from too many modules: {} imports from {} (limit {})",
+                    file, count, limit
+                )
+            }
+            ValidationFailure::FileFanInExceeded(file, count, limit) => {
+                write!(
+                    f,
+                    "File is imported by too many modules: {} is imported by {} (limit {})",
+                    file, count, limit
+                )
             }
         }
     }
 }
 
+pub enum ValidateOutcome {
+    Passed,
+    Failed,
+}
+
 impl ValidateCommand {
-    pub fn run(self) -> Result<(), ValidateCommandError> {
+    pub fn run(self) -> Result<ValidateOutcome, ValidateCommandError> {
         let run_all = !self.circular_modules
             && !self.circular_files
             && !self.external_barrel_imports
-            && !self.barrel_imports_barrel;
-
-        if run_all
-            || self.circular_modules
-            || self.circular_files
-            || self.external_barrel_imports
-            || self.barrel_imports_barrel
-        {
-            let module_mappings: ModuleMappings =
-                ModuleMappings::from_param_strings(self.module_mapping)?;
-
-            let repository: Repository = Repository::new(
-                self.repository_path.try_into()?,
-                module_mappings,
-                self.skip_folders,
-            );
+            && !self.barrel_imports_barrel
+            && !self.own_barrel_imports
+            && !self.unresolved_imports
+            && !self.unused_barrel_exports
+            && !self.unreachable_files
+            && !self.fan_limits;
+
+        let fan_limits = FanLimits {
+            max_module_fan_out: self.max_module_fan_out,
+            max_module_fan_in: self.max_module_fan_in,
+            max_file_fan_out: self.max_file_fan_out,
+            max_file_fan_in: self.max_file_fan_in,
+        };
+
+        let module_mappings: ModuleMappings =
+            ModuleMappings::from_param_strings(self.module_mapping)?;
+
+        let repository: Repository = Repository::new(
+            self.repository_path.clone().try_into()?,
+            module_mappings,
+            self.skip_folders,
+        );
+
+        let files = repository.analyzed_files();
+        let DependencyAnalysis {
+            dependencies,
+            diagnostics,
+        } = DependencyAnalysis::<RepositoryChildPath, RepositoryChildPath>::from(&files);
+
+        let mut results: Vec<(ValidationCheck, Vec<ValidationFailure>)> = vec![];
+
+        if run_all || self.circular_modules {
+            results.push((
+                ValidationCheck::CircularModules,
+                validate_circular_modules(&dependencies, self.cycles),
+            ));
+        }
 
-            if run_all || self.circular_modules {
-                println!("Running circular module validation");
-                let failures = validate_circular_modules(repository.clone())?;
+        if run_all || self.circular_files {
+            results.push((
+                ValidationCheck::CircularFiles,
+                validate_circular_files(&dependencies, self.cycles),
+            ));
+        }
 
-                for failure in failures.iter() {
-                    println!("{}", failure);
-                }
+        if run_all || self.external_barrel_imports {
+            results.push((
+                ValidationCheck::ExternalBarrelImports,
+                validate_external_barrel_imports(&dependencies),
+            ));
+        }
 
-                println!("\n\nTotal: {}", failures.len());
-            }
+        if run_all || self.barrel_imports_barrel {
+            results.push((
+                ValidationCheck::BarrelImportsBarrel,
+                validate_barrel_imports_barrel(&dependencies),
+            ));
+        }
 
-            if run_all || self.circular_files {
-                println!("\nRunning circular file validation");
-                let failures = validate_circular_files(repository.clone())?;
+        if run_all || self.own_barrel_imports {
+            results.push((
+                ValidationCheck::OwnBarrelImports,
+                validate_own_barrel_imports(&dependencies),
+            ));
+        }
 
-                for failure in failures.iter() {
-                    println!("{}", failure);
-                }
+        if run_all || self.unresolved_imports {
+            results.push((
+                ValidationCheck::UnresolvedImports,
+                validate_unresolved_imports(&files)?,
+            ));
+        }
 
-                println!("\n\nTotal: {}", failures.len());
-            }
+        if run_all || self.unused_barrel_exports {
+            results.push((
+                ValidationCheck::UnusedBarrelExports,
+                validate_unused_barrel_exports(&files)?,
+            ));
+        }
+
+        if (run_all && !self.entry_point.is_empty()) || self.unreachable_files {
+            results.push((
+                ValidationCheck::UnreachableFiles,
+                validate_unreachable_files(&files, &self.entry_point)?,
+            ));
+        }
 
-            if run_all || self.external_barrel_imports {
-                println!("\nRunning external barrel import validation");
-                let failures = validate_external_barrel_imports(repository.clone())?;
+        if (run_all && !fan_limits.is_empty()) || self.fan_limits {
+            results.push((
+                ValidationCheck::FanLimits,
+                validate_fan_limits(&dependencies, &fan_limits),
+            ));
+        }
 
-                for failure in failures.iter() {
-                    println!("{}", failure);
+        let suggested_fixes = if self.suggest_fixes && (run_all || self.circular_modules) {
+            Some(suggest_cycle_breaks(&dependencies))
+        } else {
+            None
+        };
+
+        let changed_files = match &self.since {
+            Some(revision) => Some(ChangedFiles::since(&repository, revision)?),
+            None => None,
+        };
+        let is_in_scope = |check: ValidationCheck, failure: &ValidationFailure| {
+            changed_files
+                .as_ref()
+                .is_none_or(|changed_files| changed_files.is_touched(check, failure))
+        };
+
+        let suppressions = RepositorySuppressions::from_files(&files)?;
+
+        for comment in suppressions.unknown_comments() {
+            eprintln!(
+                "Warning: {}: suppression comment names no known check and suppresses nothing",
+                comment
+            );
+        }
+        let mut suppressed_counts: HashMap<ValidationCheck, usize> = HashMap::new();
+
+        for (check, failures) in results.iter_mut() {
+            let mut suppressed = 0;
+
+            failures.retain(|failure| {
+                let is_suppressed = suppressions.is_suppressed(*check, failure);
+
+                if is_suppressed && is_in_scope(*check, failure) {
+                    suppressed += 1;
                 }
 
-                println!("\n\nTotal: {}", failures.len());
-            }
+                !is_suppressed
+            });
+            suppressed_counts.insert(*check, suppressed);
+        }
+
+        let mut fixed_baseline_entries: Vec<String> = vec![];
+
+        if let Some(baseline_path) = self.baseline {
+            let checks: Vec<ValidationCheck> = results.iter().map(|(check, _)| *check).collect();
 
-            if run_all || self.barrel_imports_barrel {
-                println!("\nRunning barrel imports barrel validation");
-                let failures = validate_barrel_imports_barrel(repository.clone())?;
+            match Baseline::from_file(&baseline_path)? {
+                Some(mut baseline) if !self.update_baseline => {
+                    for (check, failures) in results.iter_mut() {
+                        failures.retain(|failure| !baseline.take(&baseline_entry(*check, failure)));
+                    }
 
-                for failure in failures.iter() {
-                    println!("{}", failure);
+                    fixed_baseline_entries = baseline
+                        .remaining(&checks)
+                        .into_iter()
+                        .map(String::from)
+                        .collect();
                 }
+                previous => {
+                    let baseline = match previous {
+                        Some(previous) => Baseline::from_failures(&results)
+                            .with_entries_of_other_checks(&previous, &checks),
+                        None => Baseline::from_failures(&results),
+                    };
+                    baseline.write(&baseline_path)?;
+
+                    match self.format {
+                        ValidateOutputFormat::Text => println!(
+                            "Baseline written to {} with {} entries",
+                            baseline_path.display(),
+                            baseline.len()
+                        ),
+                        ValidateOutputFormat::Json => json::print_baseline_written(
+                            &baseline_path.display().to_string(),
+                            baseline.len(),
+                        )?,
+                        ValidateOutputFormat::Sarif
+                        | ValidateOutputFormat::Junit
+                        | ValidateOutputFormat::Github
+                        | ValidateOutputFormat::Gitlab => eprintln!(
+                            "Baseline written to {} with {} entries",
+                            baseline_path.display(),
+                            baseline.len()
+                        ),
+                    }
+
+                    return Ok(ValidateOutcome::Passed);
+                }
+            }
+        }
 
-                println!("\n\nTotal: {}", failures.len());
+        for (check, failures) in results.iter_mut() {
+            failures.retain(|failure| is_in_scope(*check, failure));
+        }
+
+        let report = ValidationReport {
+            checks: results
+                .into_iter()
+                .map(|(check, failures)| CheckReport {
+                    check,
+                    severity: Severity::for_check(check, &self.warn),
+                    failures: failures
+                        .into_iter()
+                        .map(|failure| ReportedFailure {
+                            location: locate(check, &failure, &suppressions),
+                            failure,
+                        })
+                        .collect(),
+                    suppressed: suppressed_counts.get(&check).copied().unwrap_or_default(),
+                })
+                .collect(),
+            suggested_fixes,
+            fixed_baseline_entries,
+            diagnostics,
+            diagnostics_severity: if self.strict {
+                Severity::Error
+            } else {
+                Severity::Warning
+            },
+        };
+
+        match self.format {
+            ValidateOutputFormat::Text => print_text(&report),
+            ValidateOutputFormat::Json => json::print_report(&report)?,
+            ValidateOutputFormat::Sarif => {
+                print_diagnostics(&report.diagnostics);
+                sarif::print_report(&report, &self.repository_path)?
+            }
+            ValidateOutputFormat::Junit => {
+                print_diagnostics(&report.diagnostics);
+                junit::print_report(&report)
+            }
+            ValidateOutputFormat::Github => github::print_report(&report, &self.repository_path),
+            ValidateOutputFormat::Gitlab => {
+                print_diagnostics(&report.diagnostics);
+                gitlab::print_report(&report, &self.repository_path)?
             }
         }
 
-        Ok(())
+        if report.error_count() > 0 {
+            Ok(ValidateOutcome::Failed)
+        } else {
+            Ok(ValidateOutcome::Passed)
+        }
     }
 }
@@ -0,0 +1,35 @@
+use belmarsh::{
+    dependency::{Dependency, list::DependencyList},
+    repository::child::RepositoryChildPath,
+};
+
+use super::ValidationFailure;
+
+pub fn validate_own_barrel_imports(
+    dependencies: &DependencyList<RepositoryChildPath, RepositoryChildPath>,
+) -> Vec<ValidationFailure> {
+    let mut own_barrel_imports: Vec<&Dependency<RepositoryChildPath, RepositoryChildPath>> =
+        dependencies
+            .as_ref()
+            .iter()
+            .filter(|dependency| {
+                !dependency.from.is_module_barrel()
+                    && dependency.to.is_module_barrel()
+                    && dependency.is_internal()
+            })
+            .collect();
+
+    own_barrel_imports.sort_by(|a, b| {
+        a.from.to_string().cmp(&b.from.to_string()).then_with(|| {
+            a.location
+                .as_ref()
+                .map(|location| location.line())
+                .cmp(&b.location.as_ref().map(|location| location.line()))
+        })
+    });
+
+    own_barrel_imports
+        .into_iter()
+        .map(|dependency| ValidationFailure::OwnBarrelImport(dependency.clone()))
+        .collect()
+}
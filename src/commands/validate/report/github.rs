@@ -0,0 +1,76 @@
+use crate::commands::output::error_chain;
+
+use super::{super::check::Severity, ValidationReport, location::FailureLocation};
+
+fn escape_data(value: &str) -> String {
+    value
+        .replace('%', "%25")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
+
+fn escape_property(value: &str) -> String {
+    escape_data(value).replace(':', "%3A").replace(',', "%2C")
+}
+
+fn command(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Error => "error",
+        Severity::Warning => "warning",
+    }
+}
+
+pub fn print_report(report: &ValidationReport, repository_path: &str) {
+    for diagnostic in report.diagnostics.iter() {
+        let file_property = diagnostic
+            .path()
+            .map(|path| {
+                format!(
+                    ",file={}",
+                    escape_property(
+                        &FailureLocation::new(path.display().to_string(), None)
+                            .path_from(repository_path)
+                    )
+                )
+            })
+            .unwrap_or_default();
+
+        println!(
+            "::{} title=belmarsh{}::{}",
+            command(report.diagnostics_severity),
+            file_property,
+            escape_data(&error_chain(diagnostic.error()))
+        );
+    }
+
+    for check_report in report.checks.iter() {
+        for reported_failure in check_report.failures.iter() {
+            let mut properties = vec![format!(
+                "title={}",
+                escape_property(&format!("belmarsh {}", check_report.check.id()))
+            )];
+
+            if let Some(location) = &reported_failure.location {
+                properties.push(format!(
+                    "file={}",
+                    escape_property(&location.path_from(repository_path))
+                ));
+
+                if let Some(line) = location.line {
+                    properties.push(format!("line={}", line));
+                }
+
+                if let Some(column) = location.column {
+                    properties.push(format!("col={}", column));
+                }
+            }
+
+            println!(
+                "::{} {}::{}",
+                command(check_report.severity),
+                properties.join(","),
+                escape_data(&reported_failure.failure.to_string())
+            );
+        }
+    }
+}
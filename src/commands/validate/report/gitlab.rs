@@ -0,0 +1,81 @@
+use serde::Serialize;
+
+use super::{super::check::Severity, ValidationReport};
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+const FNV_PRIME: u64 = 0x100000001b3;
+
+#[derive(Serialize)]
+struct CodeQualityLines {
+    begin: usize,
+}
+
+#[derive(Serialize)]
+struct CodeQualityLocation {
+    path: String,
+    lines: CodeQualityLines,
+}
+
+#[derive(Serialize)]
+struct CodeQualityIssue {
+    description: String,
+    check_name: &'static str,
+    fingerprint: String,
+    severity: &'static str,
+    location: CodeQualityLocation,
+}
+
+fn fingerprint(check_id: &str, subject: &str) -> String {
+    let hash = format!("{}\t{}", check_id, subject)
+        .bytes()
+        .fold(FNV_OFFSET_BASIS, |hash, byte| {
+            (hash ^ byte as u64).wrapping_mul(FNV_PRIME)
+        });
+
+    format!("{:016x}", hash)
+}
+
+fn severity(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Error => "major",
+        Severity::Warning => "minor",
+    }
+}
+
+pub fn print_report(
+    report: &ValidationReport,
+    repository_path: &str,
+) -> Result<(), serde_json::Error> {
+    let issues: Vec<CodeQualityIssue> = report
+        .checks
+        .iter()
+        .flat_map(|check_report| {
+            check_report.failures.iter().map(|reported_failure| {
+                let failure = &reported_failure.failure;
+
+                CodeQualityIssue {
+                    description: failure.to_string(),
+                    check_name: check_report.check.id(),
+                    fingerprint: fingerprint(check_report.check.id(), &failure.subject()),
+                    severity: severity(check_report.severity),
+                    location: match &reported_failure.location {
+                        Some(location) => CodeQualityLocation {
+                            path: location.path_from(repository_path),
+                            lines: CodeQualityLines {
+                                begin: location.line.unwrap_or(1),
+                            },
+                        },
+                        None => CodeQualityLocation {
+                            path: repository_path.to_string(),
+                            lines: CodeQualityLines { begin: 1 },
+                        },
+                    },
+                }
+            })
+        })
+        .collect();
+
+    println!("{}", serde_json::to_string_pretty(&issues)?);
+
+    Ok(())
+}
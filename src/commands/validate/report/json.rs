@@ -0,0 +1,308 @@
+use serde::Serialize;
+
+use crate::commands::output::{error_chain, print_json};
+
+use super::{super::ValidationFailure, ValidationReport, location::FailureLocation};
+
+#[derive(Serialize)]
+struct JsonEdge {
+    from: String,
+    to: String,
+}
+
+/// A single failure, tagged by `kind` with the fields specific to that kind
+/// alongside the human readable `message`.
+#[derive(Serialize)]
+#[serde(tag = "kind", rename_all = "kebab-case")]
+enum JsonFailureDetails {
+    CircularDependency {
+        chain: Vec<String>,
+    },
+    TangledComponent {
+        members: Vec<String>,
+        edges: Vec<JsonEdge>,
+    },
+    ExternalBarrelImport {
+        from: String,
+        to: String,
+        line: Option<usize>,
+        column: Option<usize>,
+    },
+    BarrelImportsBarrel {
+        from: String,
+        to: String,
+        line: Option<usize>,
+        column: Option<usize>,
+    },
+    OwnBarrelImport {
+        from: String,
+        to: String,
+        line: Option<usize>,
+        column: Option<usize>,
+    },
+    UnresolvedImport {
+        file: String,
+        line: usize,
+        column: usize,
+        specifier: String,
+    },
+    UnreachableFile {
+        module: String,
+        file: String,
+    },
+    UnusedBarrelExport {
+        barrel: String,
+        line: usize,
+        name: String,
+    },
+    ModuleFanOutExceeded {
+        module: String,
+        count: usize,
+        limit: usize,
+    },
+    ModuleFanInExceeded {
+        module: String,
+        count: usize,
+        limit: usize,
+    },
+    FileFanOutExceeded {
+        file: String,
+        count: usize,
+        limit: usize,
+    },
+    FileFanInExceeded {
+        file: String,
+        count: usize,
+        limit: usize,
+    },
+}
+
+impl From<&ValidationFailure> for JsonFailureDetails {
+    fn from(failure: &ValidationFailure) -> Self {
+        match failure {
+            ValidationFailure::CircularDependency(chain) => {
+                JsonFailureDetails::CircularDependency {
+                    chain: chain.0.clone(),
+                }
+            }
+            ValidationFailure::TangledComponent(component) => {
+                JsonFailureDetails::TangledComponent {
+                    members: component.members().to_vec(),
+                    edges: component
+                        .edges()
+                        .iter()
+                        .map(|(from, to)| JsonEdge {
+                            from: from.clone(),
+                            to: to.clone(),
+                        })
+                        .collect(),
+                }
+            }
+            ValidationFailure::ExternalBarrelImport(dependency) => {
+                JsonFailureDetails::ExternalBarrelImport {
+                    from: dependency.from.to_string(),
+                    to: dependency.to.to_string(),
+                    line: dependency.location.as_ref().map(|location| location.line()),
+                    column: dependency
+                        .location
+                        .as_ref()
+                        .map(|location| location.column()),
+                }
+            }
+            ValidationFailure::BarrelImportsBarrel(dependency) => {
+                JsonFailureDetails::BarrelImportsBarrel {
+                    from: dependency.from.to_string(),
+                    to: dependency.to.to_string(),
+                    line: dependency.location.as_ref().map(|location| location.line()),
+                    column: dependency
+                        .location
+                        .as_ref()
+                        .map(|location| location.column()),
+                }
+            }
+            ValidationFailure::OwnBarrelImport(dependency) => JsonFailureDetails::OwnBarrelImport {
+                from: dependency.from.to_string(),
+                to: dependency.to.to_string(),
+                line: dependency.location.as_ref().map(|location| location.line()),
+                column: dependency
+                    .location
+                    .as_ref()
+                    .map(|location| location.column()),
+            },
+            ValidationFailure::UnresolvedImport(file, unresolved_import) => {
+                JsonFailureDetails::UnresolvedImport {
+                    file: file.to_string(),
+                    line: unresolved_import.line(),
+                    column: unresolved_import.location().column(),
+                    specifier: unresolved_import.specifier().to_string(),
+                }
+            }
+            ValidationFailure::UnreachableFile(module, file) => {
+                JsonFailureDetails::UnreachableFile {
+                    module: module.to_string(),
+                    file: file.to_string(),
+                }
+            }
+            ValidationFailure::UnusedBarrelExport(barrel, line, name) => {
+                JsonFailureDetails::UnusedBarrelExport {
+                    barrel: barrel.to_string(),
+                    line: *line,
+                    name: name.clone(),
+                }
+            }
+            ValidationFailure::ModuleFanOutExceeded(module, count, limit) => {
+                JsonFailureDetails::ModuleFanOutExceeded {
+                    module: module.to_string(),
+                    count: *count,
+                    limit: *limit,
+                }
+            }
+            ValidationFailure::ModuleFanInExceeded(module, count, limit) => {
+                JsonFailureDetails::ModuleFanInExceeded {
+                    module: module.to_string(),
+                    count: *count,
+                    limit: *limit,
+                }
+            }
+            ValidationFailure::FileFanOutExceeded(file, count, limit) => {
+                JsonFailureDetails::FileFanOutExceeded {
+                    file: file.to_string(),
+                    count: *count,
+                    limit: *limit,
+                }
+            }
+            ValidationFailure::FileFanInExceeded(file, count, limit) => {
+                JsonFailureDetails::FileFanInExceeded {
+                    file: file.to_string(),
+                    count: *count,
+                    limit: *limit,
+                }
+            }
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct JsonLocation {
+    file: String,
+    line: Option<usize>,
+    column: Option<usize>,
+}
+
+impl From<&FailureLocation> for JsonLocation {
+    fn from(location: &FailureLocation) -> Self {
+        JsonLocation {
+            file: location.file.clone(),
+            line: location.line,
+            column: location.column,
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct JsonFailure {
+    #[serde(flatten)]
+    details: JsonFailureDetails,
+    message: String,
+    location: Option<JsonLocation>,
+}
+
+/// One entry per check that ran; `check` is the id accepted by `--warn`.
+#[derive(Serialize)]
+struct JsonCheck {
+    check: &'static str,
+    severity: String,
+    failures: Vec<JsonFailure>,
+    total: usize,
+    suppressed: usize,
+}
+
+#[derive(Serialize)]
+struct JsonDiagnostic {
+    file: Option<String>,
+    severity: String,
+    message: String,
+}
+
+#[derive(Serialize)]
+struct JsonSuggestedFix {
+    from: String,
+    to: String,
+    imports: usize,
+}
+
+#[derive(Serialize)]
+struct ValidateJson {
+    checks: Vec<JsonCheck>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    suggested_fixes: Option<Vec<JsonSuggestedFix>>,
+    diagnostics: Vec<JsonDiagnostic>,
+    fixed_baseline_entries: Vec<String>,
+    errors: usize,
+    warnings: usize,
+}
+
+#[derive(Serialize)]
+struct BaselineWrittenJson {
+    baseline_written: String,
+    entries: usize,
+}
+
+pub fn print_report(report: &ValidationReport) -> Result<(), serde_json::Error> {
+    print_json(
+        "validate",
+        ValidateJson {
+            checks: report
+                .checks
+                .iter()
+                .map(|check_report| JsonCheck {
+                    check: check_report.check.id(),
+                    severity: check_report.severity.to_string(),
+                    failures: check_report
+                        .failures
+                        .iter()
+                        .map(|reported_failure| JsonFailure {
+                            details: (&reported_failure.failure).into(),
+                            message: reported_failure.failure.to_string(),
+                            location: reported_failure.location.as_ref().map(JsonLocation::from),
+                        })
+                        .collect(),
+                    total: check_report.failures.len(),
+                    suppressed: check_report.suppressed,
+                })
+                .collect(),
+            suggested_fixes: report.suggested_fixes.as_ref().map(|suggested_fixes| {
+                suggested_fixes
+                    .iter()
+                    .map(|suggested_fix| JsonSuggestedFix {
+                        from: suggested_fix.dependency.from.to_string(),
+                        to: suggested_fix.dependency.to.to_string(),
+                        imports: suggested_fix.imports,
+                    })
+                    .collect()
+            }),
+            diagnostics: report
+                .diagnostics
+                .iter()
+                .map(|diagnostic| JsonDiagnostic {
+                    file: diagnostic.path().map(|path| path.display().to_string()),
+                    severity: report.diagnostics_severity.to_string(),
+                    message: error_chain(diagnostic.error()),
+                })
+                .collect(),
+            fixed_baseline_entries: report.fixed_baseline_entries.clone(),
+            errors: report.error_count(),
+            warnings: report.warning_count(),
+        },
+    )
+}
+
+pub fn print_baseline_written(path: &str, entries: usize) -> Result<(), serde_json::Error> {
+    print_json(
+        "validate",
+        BaselineWrittenJson {
+            baseline_written: path.to_string(),
+            entries,
+        },
+    )
+}
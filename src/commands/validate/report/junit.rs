@@ -0,0 +1,135 @@
+use crate::commands::output::error_chain;
+
+use super::{super::check::Severity, CheckReport, ValidationReport};
+
+fn escape_xml(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+fn write_testsuite(output: &mut String, check_report: &CheckReport) {
+    let check_id = check_report.check.id();
+    let failures = match check_report.severity {
+        Severity::Error => check_report.failures.len(),
+        Severity::Warning => 0,
+    };
+    let skipped = check_report.failures.len() - failures;
+
+    output.push_str(&format!(
+        "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\" skipped=\"{}\">\n",
+        check_id,
+        check_report.failures.len().max(1),
+        failures,
+        skipped
+    ));
+
+    if check_report.failures.is_empty() {
+        output.push_str(&format!(
+            "    <testcase classname=\"belmarsh.{}\" name=\"{} validation\"/>\n",
+            check_id,
+            escape_xml(&check_report.check.to_string())
+        ));
+    }
+
+    for reported_failure in check_report.failures.iter() {
+        let failure = &reported_failure.failure;
+        let file_attribute = reported_failure
+            .location
+            .as_ref()
+            .map(|location| format!(" file=\"{}\"", escape_xml(&location.file)))
+            .unwrap_or_default();
+
+        output.push_str(&format!(
+            "    <testcase classname=\"belmarsh.{}\" name=\"{}\"{}>\n",
+            check_id,
+            escape_xml(&failure.subject()),
+            file_attribute
+        ));
+
+        match check_report.severity {
+            Severity::Error => output.push_str(&format!(
+                "      <failure message=\"{}\" type=\"{}\">{}</failure>\n",
+                escape_xml(&failure.to_string()),
+                failure.kind(),
+                escape_xml(&failure.subject())
+            )),
+            Severity::Warning => output.push_str(&format!(
+                "      <skipped message=\"{}\"/>\n",
+                escape_xml(&failure.to_string())
+            )),
+        }
+
+        output.push_str("    </testcase>\n");
+    }
+
+    output.push_str("  </testsuite>\n");
+}
+
+/// Files that could not be analyzed, each as a test case in error. Only
+/// written with `--strict`; otherwise they are warnings printed to stderr.
+fn write_diagnostics_testsuite(output: &mut String, report: &ValidationReport) {
+    output.push_str(&format!(
+        "  <testsuite name=\"file-analysis\" tests=\"{}\" failures=\"0\" errors=\"{}\">\n",
+        report.diagnostics.len(),
+        report.diagnostics.len()
+    ));
+
+    for diagnostic in report.diagnostics.iter() {
+        let file = diagnostic
+            .path()
+            .map(|path| path.display().to_string())
+            .unwrap_or_default();
+
+        output.push_str(&format!(
+            "    <testcase classname=\"belmarsh.file-analysis\" name=\"{}\" file=\"{}\">\n",
+            escape_xml(&diagnostic.to_string()),
+            escape_xml(&file)
+        ));
+        output.push_str(&format!(
+            "      <error message=\"{}\" type=\"file-analysis\">{}</error>\n",
+            escape_xml(&diagnostic.to_string()),
+            escape_xml(&error_chain(diagnostic.error()))
+        ));
+        output.push_str("    </testcase>\n");
+    }
+
+    output.push_str("  </testsuite>\n");
+}
+
+pub fn print_report(report: &ValidationReport) {
+    let errors = match report.diagnostics_severity {
+        Severity::Error => report.diagnostics.len(),
+        Severity::Warning => 0,
+    };
+    let tests: usize = report
+        .checks
+        .iter()
+        .map(|check_report| check_report.failures.len().max(1))
+        .sum::<usize>()
+        + errors;
+
+    let mut output = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+
+    output.push_str(&format!(
+        "<testsuites name=\"belmarsh\" tests=\"{}\" failures=\"{}\" errors=\"{}\">\n",
+        tests,
+        report.failure_count(Severity::Error),
+        errors
+    ));
+
+    for check_report in report.checks.iter() {
+        write_testsuite(&mut output, check_report);
+    }
+
+    if errors > 0 {
+        write_diagnostics_testsuite(&mut output, report);
+    }
+
+    output.push_str("</testsuites>");
+
+    println!("{}", output);
+}
@@ -0,0 +1,129 @@
+use std::path::Path;
+
+use belmarsh::import_path::ImportLocation;
+
+use super::super::{
+    ValidationFailure, check::ValidationCheck, suppression::RepositorySuppressions,
+};
+
+pub struct FailureLocation {
+    pub file: String,
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+}
+
+impl FailureLocation {
+    pub fn new(file: String, line: Option<usize>) -> Self {
+        FailureLocation {
+            file,
+            line,
+            column: None,
+        }
+    }
+
+    fn at(file: String, location: &ImportLocation) -> Self {
+        FailureLocation {
+            file,
+            line: Some(location.line()),
+            column: Some(location.column()),
+        }
+    }
+
+    pub fn path_from(&self, repository_path: &str) -> String {
+        let path = Path::new(repository_path).join(&self.file);
+        let path = path.to_string_lossy();
+
+        path.strip_prefix("./").unwrap_or(&path).to_string()
+    }
+}
+
+/// Where formats that need a file report a module level failure: the barrel
+/// of its first module when there is one, otherwise the module's directory.
+pub fn locate_module(
+    check: ValidationCheck,
+    failure: &ValidationFailure,
+    repository_path: &str,
+) -> Option<FailureLocation> {
+    let module = match failure {
+        ValidationFailure::CircularDependency(chain)
+            if check == ValidationCheck::CircularModules =>
+        {
+            chain.0.first()?.clone()
+        }
+        ValidationFailure::TangledComponent(component)
+            if check == ValidationCheck::CircularModules =>
+        {
+            component.members().first()?.clone()
+        }
+        ValidationFailure::ModuleFanOutExceeded(module, _, _)
+        | ValidationFailure::ModuleFanInExceeded(module, _, _) => module.to_string(),
+        _ => return None,
+    };
+
+    let barrel = Path::new(&module).join("index.ts");
+
+    if Path::new(repository_path).join(&barrel).is_file() {
+        Some(FailureLocation::new(barrel.display().to_string(), None))
+    } else {
+        Some(FailureLocation::new(format!("{}/", module), None))
+    }
+}
+
+pub fn locate(
+    check: ValidationCheck,
+    failure: &ValidationFailure,
+    imports: &RepositorySuppressions,
+) -> Option<FailureLocation> {
+    match failure {
+        ValidationFailure::CircularDependency(chain) => {
+            if check != ValidationCheck::CircularFiles || chain.0.len() < 2 {
+                return None;
+            }
+
+            Some(FailureLocation::new(
+                chain.0[0].clone(),
+                imports.import_line(&chain.0[0], &chain.0[1]),
+            ))
+        }
+        ValidationFailure::TangledComponent(component) => {
+            if check != ValidationCheck::CircularFiles {
+                return None;
+            }
+
+            let (from, to) = component.edges().first()?;
+
+            Some(FailureLocation::new(
+                from.clone(),
+                imports.import_line(from, to),
+            ))
+        }
+        ValidationFailure::ExternalBarrelImport(dependency)
+        | ValidationFailure::BarrelImportsBarrel(dependency)
+        | ValidationFailure::OwnBarrelImport(dependency) => {
+            let from = dependency.from.to_string();
+
+            match &dependency.location {
+                Some(location) => Some(FailureLocation::at(from, location)),
+                None => {
+                    let line = imports.import_line(&from, &dependency.to.to_string());
+
+                    Some(FailureLocation::new(from, line))
+                }
+            }
+        }
+        ValidationFailure::UnresolvedImport(file, unresolved_import) => Some(FailureLocation::at(
+            file.to_string(),
+            unresolved_import.location(),
+        )),
+        ValidationFailure::UnusedBarrelExport(barrel, line, _) => {
+            Some(FailureLocation::new(barrel.to_string(), Some(*line)))
+        }
+        ValidationFailure::UnreachableFile(_, file)
+        | ValidationFailure::FileFanOutExceeded(file, _, _)
+        | ValidationFailure::FileFanInExceeded(file, _, _) => {
+            Some(FailureLocation::new(file.to_string(), None))
+        }
+        ValidationFailure::ModuleFanOutExceeded(_, _, _)
+        | ValidationFailure::ModuleFanInExceeded(_, _, _) => None,
+    }
+}
@@ -0,0 +1,141 @@
+pub mod github;
+pub mod gitlab;
+pub mod json;
+pub mod junit;
+pub mod location;
+pub mod sarif;
+
+use belmarsh::{
+    dependency::{Dependency, analysis::FileDiagnostic},
+    module::Module,
+};
+
+use crate::commands::output::error_chain;
+
+use super::{
+    ValidationFailure,
+    check::{Severity, ValidationCheck},
+};
+use location::FailureLocation;
+
+pub struct ReportedFailure {
+    pub failure: ValidationFailure,
+    pub location: Option<FailureLocation>,
+}
+
+pub struct CheckReport {
+    pub check: ValidationCheck,
+    pub severity: Severity,
+    pub failures: Vec<ReportedFailure>,
+    pub suppressed: usize,
+}
+
+/// A module dependency whose removal helps break every module cycle, with the
+/// number of file imports that would have to change.
+pub struct SuggestedFix {
+    pub dependency: Dependency<Module, Module>,
+    pub imports: usize,
+}
+
+pub struct ValidationReport {
+    pub checks: Vec<CheckReport>,
+    pub suggested_fixes: Option<Vec<SuggestedFix>>,
+    pub fixed_baseline_entries: Vec<String>,
+    pub diagnostics: Vec<FileDiagnostic>,
+    pub diagnostics_severity: Severity,
+}
+
+impl ValidationReport {
+    pub fn failure_count(&self, severity: Severity) -> usize {
+        self.checks
+            .iter()
+            .filter(|check_report| check_report.severity == severity)
+            .map(|check_report| check_report.failures.len())
+            .sum()
+    }
+
+    fn count(&self, severity: Severity) -> usize {
+        let diagnostics = if self.diagnostics_severity == severity {
+            self.diagnostics.len()
+        } else {
+            0
+        };
+
+        self.failure_count(severity) + diagnostics
+    }
+
+    pub fn error_count(&self) -> usize {
+        self.count(Severity::Error)
+    }
+
+    pub fn warning_count(&self) -> usize {
+        self.count(Severity::Warning)
+    }
+}
+
+pub fn print_text(report: &ValidationReport) {
+    for check_report in report.checks.iter() {
+        println!(
+            "\nRunning {} validation ({})",
+            check_report.check, check_report.severity
+        );
+
+        for reported_failure in check_report.failures.iter() {
+            println!("{}", reported_failure.failure);
+        }
+
+        println!("\n\nTotal: {}", check_report.failures.len());
+
+        if check_report.suppressed > 0 {
+            println!("Suppressed: {}", check_report.suppressed);
+        }
+    }
+
+    if let Some(suggested_fixes) = &report.suggested_fixes {
+        println!("\nModule dependencies to remove to break every module cycle");
+
+        for suggested_fix in suggested_fixes.iter() {
+            println!(
+                "{} ({} import(s))",
+                suggested_fix.dependency, suggested_fix.imports
+            );
+        }
+
+        println!(
+            "\n\nTotal imports to change: {}",
+            suggested_fixes
+                .iter()
+                .map(|suggested_fix| suggested_fix.imports)
+                .sum::<usize>()
+        );
+    }
+
+    if !report.diagnostics.is_empty() {
+        println!(
+            "\nFiles that could not be analyzed ({})",
+            report.diagnostics_severity
+        );
+
+        for diagnostic in report.diagnostics.iter() {
+            println!("{}", error_chain(diagnostic));
+        }
+
+        println!("\n\nTotal: {}", report.diagnostics.len());
+    }
+
+    if !report.fixed_baseline_entries.is_empty() {
+        println!("\nBaseline entries that have been fixed and can be removed:");
+
+        for entry in report.fixed_baseline_entries.iter() {
+            println!("{}", entry);
+        }
+
+        println!("\n\nTotal fixed: {}", report.fixed_baseline_entries.len());
+    }
+
+    println!(
+        "\nErrors: {}, Warnings: {}",
+        report.error_count(),
+        report.warning_count()
+    );
+}
@@ -0,0 +1,357 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use serde::Serialize;
+
+use super::{
+    super::check::Severity,
+    ValidationReport,
+    location::{FailureLocation, locate_module},
+};
+
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+const SARIF_VERSION: &str = "2.1.0";
+const SOURCE_ROOT: &str = "%SRCROOT%";
+
+struct RuleDefinition {
+    id: &'static str,
+    name: &'static str,
+    description: &'static str,
+    help: &'static str,
+}
+
+const RULES: &[RuleDefinition] = &[
+    RuleDefinition {
+        id: "circular-dependency",
+        name: "CircularDependency",
+        description: "Modules or files depend on each other in a cycle.",
+        help: "Break the cycle by moving the shared code into a module both sides can depend on, or by inverting one of the dependencies.",
+    },
+    RuleDefinition {
+        id: "tangled-component",
+        name: "TangledComponent",
+        description: "A group of modules or files is strongly connected through import cycles.",
+        help: "Untangle the component by removing its internal edges until no member can reach itself again.",
+    },
+    RuleDefinition {
+        id: "external-barrel-import",
+        name: "ExternalBarrelImport",
+        description: "A file imports another module without going through its barrel file.",
+        help: "Import from the module's index.ts instead of reaching into its internal files, exporting the symbol from the barrel if needed.",
+    },
+    RuleDefinition {
+        id: "barrel-imports-barrel",
+        name: "BarrelImportsBarrel",
+        description: "A barrel file imports or re-exports another barrel file.",
+        help: "Re-export the individual files from the barrel instead of chaining barrels together.",
+    },
+    RuleDefinition {
+        id: "own-barrel-import",
+        name: "OwnBarrelImport",
+        description: "A file imports the barrel file of the module it belongs to.",
+        help: "Import the sibling file directly with a relative path; importing your own barrel frequently causes undefined values at runtime.",
+    },
+    RuleDefinition {
+        id: "unresolved-import",
+        name: "UnresolvedImport",
+        description: "An import specifier does not resolve to a file.",
+        help: "Fix the import path or add a --module-mapping for the alias it uses.",
+    },
+    RuleDefinition {
+        id: "unreachable-file",
+        name: "UnreachableFile",
+        description: "A file cannot be reached from any entry point.",
+        help: "Delete the file if it is dead code, or add it as an --entry-point if it is loaded some other way.",
+    },
+    RuleDefinition {
+        id: "unused-barrel-export",
+        name: "UnusedBarrelExport",
+        description: "A barrel file exports a symbol that no other module imports.",
+        help: "Remove the export from the barrel to keep the module's public API small.",
+    },
+    RuleDefinition {
+        id: "module-fan-out-exceeded",
+        name: "ModuleFanOutExceeded",
+        description: "A module depends on more modules than allowed.",
+        help: "Split the module or move the code that needs the extra dependencies elsewhere.",
+    },
+    RuleDefinition {
+        id: "module-fan-in-exceeded",
+        name: "ModuleFanInExceeded",
+        description: "More modules depend on a module than allowed.",
+        help: "Split the module so that consumers only depend on the part they use.",
+    },
+    RuleDefinition {
+        id: "file-fan-out-exceeded",
+        name: "FileFanOutExceeded",
+        description: "A file imports from more modules than allowed.",
+        help: "Split the file so that each part only needs a few other modules.",
+    },
+    RuleDefinition {
+        id: "file-fan-in-exceeded",
+        name: "FileFanInExceeded",
+        description: "A file is imported by more modules than allowed.",
+        help: "Split the file or move it into a module of its own.",
+    },
+];
+
+#[derive(Serialize)]
+struct SarifText {
+    text: String,
+}
+
+impl SarifText {
+    fn new<T: Into<String>>(text: T) -> Self {
+        SarifText { text: text.into() }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifRule {
+    id: &'static str,
+    name: &'static str,
+    short_description: SarifText,
+    full_description: SarifText,
+    help: SarifText,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifDriver {
+    name: &'static str,
+    version: &'static str,
+    rules: Vec<SarifRule>,
+}
+
+#[derive(Serialize)]
+struct SarifTool {
+    driver: SarifDriver,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifArtifactLocation {
+    uri: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    uri_base_id: Option<&'static str>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifRegion {
+    start_line: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    start_column: Option<usize>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifPhysicalLocation {
+    artifact_location: SarifArtifactLocation,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    region: Option<SarifRegion>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifLocation {
+    physical_location: SarifPhysicalLocation,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifResult {
+    rule_id: &'static str,
+    rule_index: usize,
+    level: &'static str,
+    message: SarifText,
+    locations: Vec<SarifLocation>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifRun {
+    tool: SarifTool,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    original_uri_base_ids: BTreeMap<&'static str, SarifArtifactLocation>,
+    results: Vec<SarifResult>,
+}
+
+#[derive(Serialize)]
+struct SarifLog {
+    #[serde(rename = "$schema")]
+    schema: &'static str,
+    version: &'static str,
+    runs: Vec<SarifRun>,
+}
+
+fn level(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Error => "error",
+        Severity::Warning => "warning",
+    }
+}
+
+/// Percent-encodes everything but unreserved characters and separators so a
+/// path can be used as a URI reference.
+fn encode_uri_path(path: &str) -> String {
+    path.bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' | b'/' => {
+                (byte as char).to_string()
+            }
+            _ => format!("%{:02X}", byte),
+        })
+        .collect()
+}
+
+/// Paths relative to the working directory are given relative to
+/// `%SRCROOT%`, absolute ones as `file` URIs.
+fn artifact_location(path: &str) -> SarifArtifactLocation {
+    if Path::new(path).is_absolute() {
+        SarifArtifactLocation {
+            uri: format!("file://{}", encode_uri_path(path)),
+            uri_base_id: None,
+        }
+    } else {
+        SarifArtifactLocation {
+            uri: encode_uri_path(path),
+            uri_base_id: Some(SOURCE_ROOT),
+        }
+    }
+}
+
+fn sarif_location(location: &FailureLocation, repository_path: &str) -> SarifLocation {
+    SarifLocation {
+        physical_location: SarifPhysicalLocation {
+            artifact_location: artifact_location(&location.path_from(repository_path)),
+            region: location.line.map(|line| SarifRegion {
+                start_line: line,
+                start_column: location.column,
+            }),
+        },
+    }
+}
+
+pub fn print_report(
+    report: &ValidationReport,
+    repository_path: &str,
+) -> Result<(), serde_json::Error> {
+    let results: Vec<SarifResult> = report
+        .checks
+        .iter()
+        .flat_map(|check_report| {
+            check_report.failures.iter().map(|reported_failure| {
+                let kind = reported_failure.failure.kind();
+
+                SarifResult {
+                    rule_id: kind,
+                    rule_index: RULES
+                        .iter()
+                        .position(|rule| rule.id == kind)
+                        .unwrap_or_default(),
+                    level: level(check_report.severity),
+                    message: SarifText::new(reported_failure.failure.to_string()),
+                    locations: match &reported_failure.location {
+                        Some(location) => vec![sarif_location(location, repository_path)],
+                        None => locate_module(
+                            check_report.check,
+                            &reported_failure.failure,
+                            repository_path,
+                        )
+                        .map(|location| sarif_location(&location, repository_path))
+                        .into_iter()
+                        .collect(),
+                    },
+                }
+            })
+        })
+        .collect();
+
+    let log = SarifLog {
+        schema: SARIF_SCHEMA,
+        version: SARIF_VERSION,
+        runs: vec![SarifRun {
+            tool: SarifTool {
+                driver: SarifDriver {
+                    name: "belmarsh",
+                    version: env!("CARGO_PKG_VERSION"),
+                    rules: RULES
+                        .iter()
+                        .map(|rule| SarifRule {
+                            id: rule.id,
+                            name: rule.name,
+                            short_description: SarifText::new(rule.description),
+                            full_description: SarifText::new(rule.description),
+                            help: SarifText::new(rule.help),
+                        })
+                        .collect(),
+                },
+            },
+            original_uri_base_ids: std::env::current_dir()
+                .map(|directory| {
+                    BTreeMap::from([(
+                        SOURCE_ROOT,
+                        SarifArtifactLocation {
+                            uri: format!(
+                                "file://{}/",
+                                encode_uri_path(directory.to_string_lossy().trim_end_matches('/'))
+                            ),
+                            uri_base_id: None,
+                        },
+                    )])
+                })
+                .unwrap_or_default(),
+            results,
+        }],
+    };
+
+    println!("{}", serde_json::to_string_pretty(&log)?);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use belmarsh::module::Module;
+
+    use super::super::super::{ValidationFailure, check::ValidationCheck};
+    use super::*;
+
+    fn artifact(location: &FailureLocation, repository_path: &str) -> serde_json::Value {
+        serde_json::to_value(
+            sarif_location(location, repository_path)
+                .physical_location
+                .artifact_location,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn locations_are_relative_to_the_source_root() {
+        let location = FailureLocation::new("a b/x.ts".to_string(), Some(3));
+
+        assert_eq!(
+            artifact(&location, "./app"),
+            serde_json::json!({ "uri": "app/a%20b/x.ts", "uriBaseId": "%SRCROOT%" })
+        );
+        assert_eq!(
+            artifact(&location, "/repository"),
+            serde_json::json!({ "uri": "file:///repository/a%20b/x.ts" })
+        );
+    }
+
+    #[test]
+    fn module_failures_without_a_barrel_point_at_the_module_directory() {
+        let failure =
+            ValidationFailure::ModuleFanOutExceeded(Module::from("checkout".to_string()), 12, 10);
+        let location =
+            locate_module(ValidationCheck::FanLimits, &failure, "/does-not-exist").unwrap();
+
+        assert_eq!(
+            artifact(&location, "app"),
+            serde_json::json!({ "uri": "app/checkout/", "uriBaseId": "%SRCROOT%" })
+        );
+    }
+}
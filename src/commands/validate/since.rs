@@ -0,0 +1,84 @@
+use std::collections::HashSet;
+use std::path::Path;
+
+use belmarsh::{
+    git::{GitError, GitRepository},
+    repository::Repository,
+};
+
+use super::{ValidationFailure, check::ValidationCheck};
+
+/// Files touched since a git revision, relative to the repository root, and
+/// the modules they belong to.
+pub struct ChangedFiles {
+    files: HashSet<String>,
+    modules: HashSet<String>,
+}
+
+impl ChangedFiles {
+    pub fn since(repository: &Repository, revision: &str) -> Result<Self, GitError> {
+        let repository_path: &Path = repository.path().as_ref();
+        let files: HashSet<String> = GitRepository::discover(repository_path)?
+            .changed_files_since(revision)?
+            .into_iter()
+            .filter_map(|file| {
+                file.strip_prefix(repository_path)
+                    .ok()
+                    .map(|relative_path| relative_path.display().to_string())
+            })
+            .collect();
+        let modules: HashSet<String> = files
+            .iter()
+            .filter_map(|file| Path::new(file).components().next())
+            .map(|component| component.as_os_str().to_string_lossy().into_owned())
+            .collect();
+
+        Ok(ChangedFiles { files, modules })
+    }
+
+    fn contains_file(&self, file: &str) -> bool {
+        self.files.contains(file)
+    }
+
+    fn contains_module(&self, module: &str) -> bool {
+        self.modules.contains(module)
+    }
+
+    fn contains_member(&self, check: ValidationCheck, member: &str) -> bool {
+        match check {
+            ValidationCheck::CircularModules => self.contains_module(member),
+            _ => self.contains_file(member),
+        }
+    }
+
+    /// Whether a failure involves at least one of the changed files, or one of
+    /// their modules for module level failures.
+    pub fn is_touched(&self, check: ValidationCheck, failure: &ValidationFailure) -> bool {
+        match failure {
+            ValidationFailure::CircularDependency(chain) => chain
+                .0
+                .iter()
+                .any(|member| self.contains_member(check, member)),
+            ValidationFailure::TangledComponent(component) => component
+                .members()
+                .iter()
+                .any(|member| self.contains_member(check, member)),
+            ValidationFailure::ExternalBarrelImport(dependency)
+            | ValidationFailure::BarrelImportsBarrel(dependency)
+            | ValidationFailure::OwnBarrelImport(dependency) => {
+                self.contains_file(&dependency.from.to_string())
+            }
+            ValidationFailure::UnresolvedImport(file, _)
+            | ValidationFailure::UnreachableFile(_, file)
+            | ValidationFailure::UnusedBarrelExport(file, _, _)
+            | ValidationFailure::FileFanOutExceeded(file, _, _)
+            | ValidationFailure::FileFanInExceeded(file, _, _) => {
+                self.contains_file(&file.to_string())
+            }
+            ValidationFailure::ModuleFanOutExceeded(module, _, _)
+            | ValidationFailure::ModuleFanInExceeded(module, _, _) => {
+                self.contains_module(module.as_ref())
+            }
+        }
+    }
+}
@@ -0,0 +1,243 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::path::Path;
+
+use belmarsh::{
+    repository::{
+        RepositoryFiles,
+        child::{
+            RepositoryChildPath, RepositoryChildPathFromImportPathError,
+            RepositoryChildPathFromPathError, RepositoryChildPathFromRepositoryFileError,
+        },
+    },
+    suppression::Suppressions,
+};
+use clap::ValueEnum;
+use rayon::prelude::*;
+
+use super::{ValidationFailure, check::ValidationCheck};
+
+#[derive(Debug)]
+pub enum ValidateSuppressionsError {
+    CouldNotLocateFileWithinRepository(RepositoryChildPathFromRepositoryFileError),
+}
+
+impl Display for ValidateSuppressionsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ValidateSuppressionsError::CouldNotLocateFileWithinRepository(_) => {
+                write!(f, "could not locate file within repository")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ValidateSuppressionsError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ValidateSuppressionsError::CouldNotLocateFileWithinRepository(e) => Some(e),
+        }
+    }
+}
+
+impl From<RepositoryChildPathFromRepositoryFileError> for ValidateSuppressionsError {
+    fn from(value: RepositoryChildPathFromRepositoryFileError) -> Self {
+        ValidateSuppressionsError::CouldNotLocateFileWithinRepository(value)
+    }
+}
+
+struct FileSuppressions {
+    suppressions: Suppressions,
+    /// Imported file, line of the specifier and line of the statement.
+    import_lines: Vec<(String, usize, usize)>,
+    unknown_lines: Vec<usize>,
+}
+
+pub struct RepositorySuppressions(HashMap<String, FileSuppressions>);
+
+/// The barrel file module level failures are suppressed from.
+fn module_barrel(module: &str) -> String {
+    Path::new(module).join("index.ts").display().to_string()
+}
+
+impl RepositorySuppressions {
+    pub fn from_files(files: &RepositoryFiles) -> Result<Self, ValidateSuppressionsError> {
+        let check_ids: Vec<&str> = ValidationCheck::value_variants()
+            .iter()
+            .map(ValidationCheck::id)
+            .collect();
+        let files: Vec<(String, FileSuppressions)> = files
+            .readable()
+            .map(
+                |analyzed_file| -> Result<(String, FileSuppressions), ValidateSuppressionsError> {
+                    let file = RepositoryChildPath::from_repository_file(analyzed_file)?;
+                    let mut suppressions =
+                        analyzed_file.suppressions().cloned().unwrap_or_default();
+                    let unknown_lines = suppressions.retain_known_checks(&check_ids);
+                    let import_lines = analyzed_file
+                        .imports()
+                        .unwrap_or_default()
+                        .iter()
+                        .filter_map(|import_path| {
+                            match RepositoryChildPath::from_import_path(import_path, analyzed_file)
+                            {
+                                Ok(to) => Some((
+                                    to.to_string(),
+                                    import_path.line(),
+                                    import_path.statement_line(),
+                                )),
+                                Err(RepositoryChildPathFromImportPathError::Path(
+                                    RepositoryChildPathFromPathError::ImportOutsideRoot(_),
+                                )) => None,
+                            }
+                        })
+                        .collect();
+
+                    Ok((
+                        file.to_string(),
+                        FileSuppressions {
+                            suppressions,
+                            import_lines,
+                            unknown_lines,
+                        },
+                    ))
+                },
+            )
+            .collect::<Result<Vec<(String, FileSuppressions)>, ValidateSuppressionsError>>()?;
+
+        Ok(RepositorySuppressions(files.into_iter().collect()))
+    }
+
+    /// `file:line` of every comment that names no known check.
+    pub fn unknown_comments(&self) -> Vec<String> {
+        let mut unknown_comments: Vec<String> = self
+            .0
+            .iter()
+            .flat_map(|(file, file_suppressions)| {
+                file_suppressions
+                    .unknown_lines
+                    .iter()
+                    .map(move |line| format!("{}:{}", file, line))
+            })
+            .collect();
+
+        unknown_comments.sort();
+        unknown_comments
+    }
+
+    fn is_file_suppressed(&self, check: ValidationCheck, file: &str) -> bool {
+        self.0.get(file).is_some_and(|file_suppressions| {
+            file_suppressions
+                .suppressions
+                .is_file_suppressed(check.id())
+        })
+    }
+
+    fn is_line_suppressed(&self, check: ValidationCheck, file: &str, line: usize) -> bool {
+        self.0.get(file).is_some_and(|file_suppressions| {
+            file_suppressions
+                .suppressions
+                .is_line_suppressed(check.id(), line)
+        })
+    }
+
+    pub fn import_line(&self, from: &str, to: &str) -> Option<usize> {
+        self.0.get(from).and_then(|file_suppressions| {
+            file_suppressions
+                .import_lines
+                .iter()
+                .find(|(import_to, _, _)| import_to == to)
+                .map(|(_, _, statement_line)| *statement_line)
+        })
+    }
+
+    /// Line of the statement importing `to` whose specifier is on `line`.
+    fn statement_line(&self, from: &str, to: &str, line: usize) -> Option<usize> {
+        self.0.get(from).and_then(|file_suppressions| {
+            file_suppressions
+                .import_lines
+                .iter()
+                .find(|(import_to, import_line, _)| import_to == to && *import_line == line)
+                .map(|(_, _, statement_line)| *statement_line)
+        })
+    }
+
+    fn is_dependency_suppressed(&self, check: ValidationCheck, from: &str, to: &str) -> bool {
+        self.0.get(from).is_some_and(|file_suppressions| {
+            file_suppressions
+                .import_lines
+                .iter()
+                .filter(|(import_to, _, _)| import_to == to)
+                .any(|(_, _, statement_line)| {
+                    file_suppressions
+                        .suppressions
+                        .is_line_suppressed(check.id(), *statement_line)
+                })
+                || file_suppressions
+                    .suppressions
+                    .is_file_suppressed(check.id())
+        })
+    }
+
+    fn is_module_suppressed(&self, check: ValidationCheck, module: &str) -> bool {
+        self.is_file_suppressed(check, &module_barrel(module))
+    }
+
+    pub fn is_suppressed(&self, check: ValidationCheck, failure: &ValidationFailure) -> bool {
+        match failure {
+            ValidationFailure::CircularDependency(chain) => match check {
+                ValidationCheck::CircularModules => chain
+                    .0
+                    .iter()
+                    .any(|module| self.is_module_suppressed(check, module)),
+                _ => chain
+                    .0
+                    .windows(2)
+                    .any(|edge| self.is_dependency_suppressed(check, &edge[0], &edge[1])),
+            },
+            ValidationFailure::TangledComponent(component) => match check {
+                ValidationCheck::CircularModules => component
+                    .members()
+                    .iter()
+                    .any(|module| self.is_module_suppressed(check, module)),
+                _ => component
+                    .edges()
+                    .iter()
+                    .any(|(from, to)| self.is_dependency_suppressed(check, from, to)),
+            },
+            ValidationFailure::ExternalBarrelImport(dependency)
+            | ValidationFailure::BarrelImportsBarrel(dependency) => self.is_dependency_suppressed(
+                check,
+                &dependency.from.to_string(),
+                &dependency.to.to_string(),
+            ),
+            ValidationFailure::OwnBarrelImport(dependency) => {
+                let from = dependency.from.to_string();
+                let to = dependency.to.to_string();
+
+                match dependency
+                    .location
+                    .as_ref()
+                    .and_then(|location| self.statement_line(&from, &to, location.line()))
+                {
+                    Some(statement_line) => self.is_line_suppressed(check, &from, statement_line),
+                    None => self.is_dependency_suppressed(check, &from, &to),
+                }
+            }
+            ValidationFailure::UnresolvedImport(file, unresolved_import) => self
+                .is_line_suppressed(check, &file.to_string(), unresolved_import.statement_line()),
+            ValidationFailure::UnusedBarrelExport(barrel, line, _) => {
+                self.is_line_suppressed(check, &barrel.to_string(), *line)
+            }
+            ValidationFailure::UnreachableFile(_, file)
+            | ValidationFailure::FileFanOutExceeded(file, _, _)
+            | ValidationFailure::FileFanInExceeded(file, _, _) => {
+                self.is_file_suppressed(check, &file.to_string())
+            }
+            ValidationFailure::ModuleFanOutExceeded(module, _, _)
+            | ValidationFailure::ModuleFanInExceeded(module, _, _) => {
+                self.is_module_suppressed(check, module.as_ref())
+            }
+        }
+    }
+}
@@ -0,0 +1,114 @@
+use belmarsh::{
+    dependency::{analysis::DependencyAnalysis, list::DependencyList},
+    repository::{
+        RepositoryFiles,
+        child::{RepositoryChildPath, RepositoryChildPathFromRepositoryFileError},
+    },
+};
+use globset::{Glob, GlobSet, GlobSetBuilder};
+use rayon::prelude::*;
+use std::collections::HashSet;
+use std::fmt::Display;
+
+use super::ValidationFailure;
+
+#[derive(Debug)]
+pub enum ValidateUnreachableFilesError {
+    InvalidEntryPoint(globset::Error),
+    CouldNotLocateFileWithinRepository(RepositoryChildPathFromRepositoryFileError),
+}
+
+impl Display for ValidateUnreachableFilesError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ValidateUnreachableFilesError::InvalidEntryPoint(_) => {
+                write!(f, "invalid --entry-point glob")
+            }
+            ValidateUnreachableFilesError::CouldNotLocateFileWithinRepository(_) => {
+                write!(f, "could not locate file within repository")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ValidateUnreachableFilesError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ValidateUnreachableFilesError::InvalidEntryPoint(e) => Some(e),
+            ValidateUnreachableFilesError::CouldNotLocateFileWithinRepository(e) => Some(e),
+        }
+    }
+}
+
+impl From<globset::Error> for ValidateUnreachableFilesError {
+    fn from(value: globset::Error) -> Self {
+        ValidateUnreachableFilesError::InvalidEntryPoint(value)
+    }
+}
+
+impl From<RepositoryChildPathFromRepositoryFileError> for ValidateUnreachableFilesError {
+    fn from(value: RepositoryChildPathFromRepositoryFileError) -> Self {
+        ValidateUnreachableFilesError::CouldNotLocateFileWithinRepository(value)
+    }
+}
+
+fn is_entry_point(entry_points: &GlobSet, file: &RepositoryChildPath) -> bool {
+    let path = file.as_ref();
+
+    entry_points.is_match(path)
+        || path
+            .file_name()
+            .is_some_and(|file_name| entry_points.is_match(file_name))
+}
+
+pub fn validate_unreachable_files(
+    repository_files: &RepositoryFiles,
+    entry_point_globs: &[String],
+) -> Result<Vec<ValidationFailure>, ValidateUnreachableFilesError> {
+    let entry_points: GlobSet = entry_point_globs
+        .iter()
+        .try_fold(GlobSetBuilder::new(), |mut builder, entry_point_glob| {
+            builder.add(Glob::new(entry_point_glob)?);
+            Ok::<GlobSetBuilder, globset::Error>(builder)
+        })?
+        .build()?;
+
+    let files: Vec<RepositoryChildPath> = repository_files
+        .readable()
+        .map(|analyzed_file| {
+            RepositoryChildPath::from_repository_file(analyzed_file)
+                .map_err(ValidateUnreachableFilesError::from)
+        })
+        .collect::<Result<Vec<RepositoryChildPath>, ValidateUnreachableFilesError>>()?;
+
+    let dependencies: DependencyList<RepositoryChildPath, RepositoryChildPath> =
+        DependencyAnalysis::with_re_exports(repository_files).dependencies;
+
+    let reachable: HashSet<RepositoryChildPath> = dependencies.reachable_from(
+        files
+            .iter()
+            .filter(|file| is_entry_point(&entry_points, file))
+            .cloned(),
+    );
+
+    let mut unreachable: Vec<(String, RepositoryChildPath)> = files
+        .into_iter()
+        .filter(|file| !reachable.contains(file))
+        .map(|file| {
+            let module = file.module().map(|m| m.to_string()).unwrap_or_default();
+
+            (module, file)
+        })
+        .collect();
+
+    unreachable.sort_by(|(a_module, a_file), (b_module, b_file)| {
+        a_module
+            .cmp(b_module)
+            .then_with(|| a_file.to_string().cmp(&b_file.to_string()))
+    });
+
+    Ok(unreachable
+        .into_iter()
+        .map(|(module, file)| ValidationFailure::UnreachableFile(module.into(), file))
+        .collect())
+}
@@ -0,0 +1,65 @@
+use belmarsh::repository::{
+    RepositoryFiles,
+    child::{RepositoryChildPath, RepositoryChildPathFromRepositoryFileError},
+};
+use rayon::prelude::*;
+use std::fmt::Display;
+
+use super::ValidationFailure;
+
+#[derive(Debug)]
+pub enum ValidateUnresolvedImportsError {
+    CouldNotLocateFileWithinRepository(RepositoryChildPathFromRepositoryFileError),
+}
+
+impl Display for ValidateUnresolvedImportsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ValidateUnresolvedImportsError::CouldNotLocateFileWithinRepository(_) => {
+                write!(f, "could not locate file within repository")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ValidateUnresolvedImportsError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ValidateUnresolvedImportsError::CouldNotLocateFileWithinRepository(e) => Some(e),
+        }
+    }
+}
+
+impl From<RepositoryChildPathFromRepositoryFileError> for ValidateUnresolvedImportsError {
+    fn from(value: RepositoryChildPathFromRepositoryFileError) -> Self {
+        ValidateUnresolvedImportsError::CouldNotLocateFileWithinRepository(value)
+    }
+}
+
+pub fn validate_unresolved_imports(
+    files: &RepositoryFiles,
+) -> Result<Vec<ValidationFailure>, ValidateUnresolvedImportsError> {
+    let failures: Vec<Vec<ValidationFailure>> = files
+        .readable()
+        .map(
+            |analyzed_file| -> Result<Vec<ValidationFailure>, ValidateUnresolvedImportsError> {
+                let unresolved_imports = analyzed_file.unresolved_imports().unwrap_or_default();
+
+                if unresolved_imports.is_empty() {
+                    return Ok(vec![]);
+                }
+
+                let file = RepositoryChildPath::from_repository_file(analyzed_file)?;
+
+                Ok(unresolved_imports
+                    .iter()
+                    .map(|unresolved_import| {
+                        ValidationFailure::UnresolvedImport(file.clone(), unresolved_import.clone())
+                    })
+                    .collect())
+            },
+        )
+        .collect::<Result<Vec<Vec<ValidationFailure>>, ValidateUnresolvedImportsError>>()?;
+
+    Ok(failures.into_iter().flatten().collect())
+}
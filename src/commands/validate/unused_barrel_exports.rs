@@ -0,0 +1,340 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt::Display;
+
+use belmarsh::{
+    import_path::{ImportKind, ImportSymbols},
+    repository::{
+        RepositoryFiles,
+        child::{
+            RepositoryChildPath, RepositoryChildPathFromImportPathError,
+            RepositoryChildPathFromPathError, RepositoryChildPathFromRepositoryFileError,
+        },
+        file::DeclaredExport,
+    },
+};
+use rayon::prelude::*;
+
+use super::ValidationFailure;
+
+#[derive(Debug)]
+pub enum ValidateUnusedBarrelExportsError {
+    CouldNotLocateFileWithinRepository(RepositoryChildPathFromRepositoryFileError),
+}
+
+impl Display for ValidateUnusedBarrelExportsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ValidateUnusedBarrelExportsError::CouldNotLocateFileWithinRepository(_) => {
+                write!(f, "could not locate file within repository")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ValidateUnusedBarrelExportsError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ValidateUnusedBarrelExportsError::CouldNotLocateFileWithinRepository(e) => Some(e),
+        }
+    }
+}
+
+impl From<RepositoryChildPathFromRepositoryFileError> for ValidateUnusedBarrelExportsError {
+    fn from(value: RepositoryChildPathFromRepositoryFileError) -> Self {
+        ValidateUnusedBarrelExportsError::CouldNotLocateFileWithinRepository(value)
+    }
+}
+
+struct FileImport {
+    from: RepositoryChildPath,
+    to: RepositoryChildPath,
+    line: usize,
+    kind: ImportKind,
+    symbols: ImportSymbols,
+}
+
+impl FileImport {
+    fn is_external(&self) -> bool {
+        match (self.from.module(), self.to.module()) {
+            (Ok(from_module), Ok(to_module)) => from_module != to_module,
+            _ => false,
+        }
+    }
+}
+
+struct AnalyzedFile {
+    file: RepositoryChildPath,
+    imports: Vec<FileImport>,
+    declared_exports: Vec<DeclaredExport>,
+}
+
+impl FileImport {
+    fn exported_names(&self) -> Vec<&str> {
+        match &self.symbols {
+            ImportSymbols::Named(symbols) => symbols.iter().map(|symbol| symbol.local()).collect(),
+            ImportSymbols::Namespace(name) => vec![name.as_str()],
+            ImportSymbols::All => vec![],
+        }
+    }
+}
+
+/// Every name `file` exports, following `export * from` through as many files
+/// as it takes. Files already in `visited` are skipped so re-export cycles end.
+fn all_exported_names<'a>(
+    file: &'a RepositoryChildPath,
+    exported_names: &HashMap<&'a RepositoryChildPath, Vec<&'a str>>,
+    star_re_exports: &HashMap<&'a RepositoryChildPath, Vec<&'a RepositoryChildPath>>,
+    visited: &mut HashSet<&'a RepositoryChildPath>,
+) -> Vec<&'a str> {
+    if !visited.insert(file) {
+        return vec![];
+    }
+
+    let mut names = exported_names.get(file).cloned().unwrap_or_default();
+
+    for re_exported_file in star_re_exports.get(file).into_iter().flatten() {
+        names.extend(all_exported_names(
+            re_exported_file,
+            exported_names,
+            star_re_exports,
+            visited,
+        ));
+    }
+
+    names
+}
+
+pub fn validate_unused_barrel_exports(
+    files: &RepositoryFiles,
+) -> Result<Vec<ValidationFailure>, ValidateUnusedBarrelExportsError> {
+    let analyzed_files: Vec<AnalyzedFile> = files
+        .readable()
+        .map(
+            |analyzed_file| -> Result<AnalyzedFile, ValidateUnusedBarrelExportsError> {
+                let from = RepositoryChildPath::from_repository_file(analyzed_file)?;
+                let mut file_imports = vec![];
+
+                for import_path in analyzed_file.imports().unwrap_or_default() {
+                    let to = match RepositoryChildPath::from_import_path(import_path, analyzed_file)
+                    {
+                        Ok(to) => to,
+                        Err(RepositoryChildPathFromImportPathError::Path(
+                            RepositoryChildPathFromPathError::ImportOutsideRoot(_),
+                        )) => continue,
+                    };
+
+                    file_imports.push(FileImport {
+                        from: from.clone(),
+                        to,
+                        line: import_path.statement_line(),
+                        kind: import_path.kind(),
+                        symbols: import_path.symbols().clone(),
+                    });
+                }
+
+                Ok(AnalyzedFile {
+                    file: from,
+                    imports: file_imports,
+                    declared_exports: analyzed_file
+                        .declared_exports()
+                        .unwrap_or_default()
+                        .to_vec(),
+                })
+            },
+        )
+        .collect::<Result<Vec<AnalyzedFile>, ValidateUnusedBarrelExportsError>>()?;
+
+    let mut file_imports: Vec<FileImport> = vec![];
+    let mut declared_exports: HashMap<RepositoryChildPath, Vec<DeclaredExport>> = HashMap::new();
+
+    for analyzed_file in analyzed_files {
+        file_imports.extend(analyzed_file.imports);
+        declared_exports.insert(analyzed_file.file, analyzed_file.declared_exports);
+    }
+
+    // What a file exports under its own name and the files it re-exports
+    // everything from, used to expand `export * from`
+    let mut exported_names: HashMap<&RepositoryChildPath, Vec<&str>> = declared_exports
+        .iter()
+        .map(|(file, exports)| (file, exports.iter().map(DeclaredExport::name).collect()))
+        .collect();
+    let mut star_re_exports: HashMap<&RepositoryChildPath, Vec<&RepositoryChildPath>> =
+        HashMap::new();
+
+    for file_import in file_imports
+        .iter()
+        .filter(|file_import| file_import.kind == ImportKind::ReExport)
+    {
+        match &file_import.symbols {
+            ImportSymbols::All => star_re_exports
+                .entry(&file_import.from)
+                .or_default()
+                .push(&file_import.to),
+            _ => exported_names
+                .entry(&file_import.from)
+                .or_default()
+                .extend(file_import.exported_names()),
+        }
+    }
+
+    let mut used_exports: HashMap<&RepositoryChildPath, HashSet<&str>> = HashMap::new();
+    let mut fully_used_barrels: HashSet<&RepositoryChildPath> = HashSet::new();
+
+    for file_import in file_imports
+        .iter()
+        .filter(|file_import| file_import.to.is_module_barrel() && file_import.is_external())
+    {
+        match &file_import.symbols {
+            ImportSymbols::Named(symbols) => used_exports
+                .entry(&file_import.to)
+                .or_default()
+                .extend(symbols.iter().map(|symbol| symbol.imported())),
+            ImportSymbols::Namespace(_) | ImportSymbols::All => {
+                fully_used_barrels.insert(&file_import.to);
+            }
+        }
+    }
+
+    let re_exports = file_imports
+        .iter()
+        .filter(|file_import| {
+            file_import.kind == ImportKind::ReExport && file_import.from.is_module_barrel()
+        })
+        .flat_map(|file_import| {
+            let names = match &file_import.symbols {
+                ImportSymbols::All => all_exported_names(
+                    &file_import.to,
+                    &exported_names,
+                    &star_re_exports,
+                    &mut HashSet::from([&file_import.from]),
+                ),
+                _ => file_import.exported_names(),
+            };
+
+            names
+                .into_iter()
+                .map(|name| (&file_import.from, file_import.line, name))
+                .collect::<Vec<(&RepositoryChildPath, usize, &str)>>()
+        });
+    let barrel_declarations = declared_exports
+        .iter()
+        .filter(|(file, _)| file.is_module_barrel())
+        .flat_map(|(barrel, exports)| {
+            exports
+                .iter()
+                .map(move |export| (barrel, export.line(), export.name()))
+        });
+
+    let mut failures: Vec<(&RepositoryChildPath, usize, &str)> = re_exports
+        .chain(barrel_declarations)
+        .filter(|(barrel, _, name)| {
+            !fully_used_barrels.contains(barrel)
+                && !used_exports
+                    .get(barrel)
+                    .is_some_and(|used_names| used_names.contains(name))
+        })
+        .collect();
+
+    failures.sort_by(|(a_barrel, a_line, a_name), (b_barrel, b_line, b_name)| {
+        a_barrel
+            .to_string()
+            .cmp(&b_barrel.to_string())
+            .then(a_line.cmp(b_line))
+            .then(a_name.cmp(b_name))
+    });
+    failures.dedup();
+
+    Ok(failures
+        .into_iter()
+        .map(|(barrel, line, name)| {
+            ValidationFailure::UnusedBarrelExport(barrel.clone(), line, name.to_string())
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use belmarsh::repository::Repository;
+
+    use super::*;
+
+    struct TestRepository {
+        path: PathBuf,
+    }
+
+    impl TestRepository {
+        fn new(name: &str, files: &[(&str, &str)]) -> Self {
+            let path = std::env::temp_dir().join(format!(
+                "belmarsh-unused-exports-{}-{}",
+                std::process::id(),
+                name
+            ));
+            let _ = std::fs::remove_dir_all(&path);
+
+            for (file, contents) in files {
+                let file_path = path.join(file);
+                std::fs::create_dir_all(file_path.parent().unwrap()).unwrap();
+                std::fs::write(file_path, contents).unwrap();
+            }
+
+            TestRepository { path }
+        }
+
+        fn unused_exports(&self) -> Vec<(String, usize, String)> {
+            let repository = Repository::try_from(self.path.display().to_string()).unwrap();
+
+            validate_unused_barrel_exports(&repository.analyzed_files())
+                .unwrap()
+                .into_iter()
+                .map(|failure| match failure {
+                    ValidationFailure::UnusedBarrelExport(barrel, line, name) => {
+                        (barrel.to_string(), line, name)
+                    }
+                    failure => panic!("unexpected failure {}", failure),
+                })
+                .collect()
+        }
+    }
+
+    impl Drop for TestRepository {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.path);
+        }
+    }
+
+    #[test]
+    fn reports_exports_declared_in_the_barrel() {
+        let repository = TestRepository::new(
+            "declared",
+            &[
+                ("a/index.ts", "export const E = 1;\nexport const F = 2;\n"),
+                ("b/b.ts", "import { F } from '../a';\n"),
+            ],
+        );
+
+        assert_eq!(
+            repository.unused_exports(),
+            vec![("a/index.ts".to_string(), 1, "E".to_string())]
+        );
+    }
+
+    #[test]
+    fn expands_nested_star_re_exports() {
+        let repository = TestRepository::new(
+            "nested",
+            &[
+                ("a/index.ts", "export * from './x';\n"),
+                ("a/x.ts", "export * from './y';\nexport const X = 1;\n"),
+                ("a/y.ts", "export * from './x';\nexport const Y = 1;\n"),
+                ("b/b.ts", "import { X } from '../a';\n"),
+            ],
+        );
+
+        assert_eq!(
+            repository.unused_exports(),
+            vec![("a/index.ts".to_string(), 1, "Y".to_string())]
+        );
+    }
+}
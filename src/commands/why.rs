@@ -0,0 +1,268 @@
+use belmarsh::{
+    dependency::{
+        Dependency,
+        analysis::DependencyAnalysis,
+        list::{DependencyList, DependencyListFromRepositoryError},
+    },
+    module::Module,
+    module_mapping::{ModuleMappings, ModuleMappingsFromParamStringsError},
+    repository::{
+        Repository, RepositoryFromStringError, child::RepositoryChildPath,
+        path::RepositoryPathFromStringError,
+    },
+};
+use clap::Args;
+use serde::Serialize;
+use std::fmt::Display;
+
+use super::output::{JsonDependency, OutputFormat, dependencies_from_analysis, print_json};
+
+#[derive(Args, Debug)]
+#[command(about = "Explain through which modules and imports one module depends on another")]
+pub struct WhyCommand {
+    repository_path: String,
+
+    from: String,
+
+    to: String,
+
+    #[arg(
+        long,
+        help = "Maximum number of shortest paths to show",
+        default_value_t = 5
+    )]
+    max_paths: usize,
+
+    #[arg(
+        long,
+        help = "Folders to skip when walking the repository (e.g. node_modules)",
+        value_name = "FOLDER_NAME",
+        default_value = "node_modules"
+    )]
+    skip_folders: Vec<String>,
+
+    #[arg(
+        long,
+        help = "Add a module mapping e.g. --module-mapping @prefix:./path/to/modules",
+        value_name = "ALIAS:PATH"
+    )]
+    module_mapping: Vec<String>,
+
+    #[arg(
+        long,
+        help = "Output format",
+        value_enum,
+        default_value_t = OutputFormat::Text
+    )]
+    format: OutputFormat,
+
+    #[arg(
+        long,
+        help = "Fail when a file cannot be analyzed instead of reporting a partial result"
+    )]
+    strict: bool,
+}
+
+#[derive(Debug)]
+pub enum WhyCommandError {
+    CouldNotParseRepository(RepositoryFromStringError),
+    CouldNotGetDependencies(DependencyListFromRepositoryError),
+    InvalidRepositoryPath(RepositoryPathFromStringError),
+    CouldNotParseModuleMapCollection(ModuleMappingsFromParamStringsError),
+    CouldNotSerializeOutput(serde_json::Error),
+    UnknownModule(Module),
+}
+
+impl Display for WhyCommandError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WhyCommandError::CouldNotParseRepository(_) => write!(f, "invalid repository"),
+            WhyCommandError::CouldNotGetDependencies(_) => {
+                write!(f, "could not collect dependencies")
+            }
+            WhyCommandError::InvalidRepositoryPath(_) => {
+                write!(f, "invalid repository path")
+            }
+            WhyCommandError::CouldNotParseModuleMapCollection(_) => {
+                write!(f, "invalid --module-mapping")
+            }
+            WhyCommandError::CouldNotSerializeOutput(_) => {
+                write!(f, "could not serialize output")
+            }
+            WhyCommandError::UnknownModule(module) => {
+                write!(f, "{} is not a module of the repository", module)
+            }
+        }
+    }
+}
+
+impl std::error::Error for WhyCommandError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            WhyCommandError::CouldNotParseRepository(e) => Some(e),
+            WhyCommandError::CouldNotGetDependencies(e) => Some(e),
+            WhyCommandError::InvalidRepositoryPath(e) => Some(e),
+            WhyCommandError::CouldNotParseModuleMapCollection(e) => Some(e),
+            WhyCommandError::CouldNotSerializeOutput(e) => Some(e),
+            WhyCommandError::UnknownModule(_) => None,
+        }
+    }
+}
+
+impl From<serde_json::Error> for WhyCommandError {
+    fn from(err: serde_json::Error) -> Self {
+        WhyCommandError::CouldNotSerializeOutput(err)
+    }
+}
+
+impl From<RepositoryFromStringError> for WhyCommandError {
+    fn from(err: RepositoryFromStringError) -> Self {
+        WhyCommandError::CouldNotParseRepository(err)
+    }
+}
+
+impl From<DependencyListFromRepositoryError> for WhyCommandError {
+    fn from(value: DependencyListFromRepositoryError) -> Self {
+        WhyCommandError::CouldNotGetDependencies(value)
+    }
+}
+
+impl From<RepositoryPathFromStringError> for WhyCommandError {
+    fn from(err: RepositoryPathFromStringError) -> Self {
+        WhyCommandError::InvalidRepositoryPath(err)
+    }
+}
+
+impl From<ModuleMappingsFromParamStringsError> for WhyCommandError {
+    fn from(err: ModuleMappingsFromParamStringsError) -> Self {
+        WhyCommandError::CouldNotParseModuleMapCollection(err)
+    }
+}
+
+struct Hop {
+    from: Module,
+    to: Module,
+    imports: Vec<Dependency<RepositoryChildPath, RepositoryChildPath>>,
+}
+
+#[derive(Serialize)]
+struct JsonHop {
+    from: String,
+    to: String,
+    imports: Vec<JsonDependency>,
+}
+
+#[derive(Serialize)]
+struct JsonPath {
+    modules: Vec<String>,
+    hops: Vec<JsonHop>,
+}
+
+#[derive(Serialize)]
+struct WhyJson {
+    from: String,
+    to: String,
+    paths: Vec<JsonPath>,
+}
+
+impl WhyCommand {
+    pub fn run(self) -> Result<(), WhyCommandError> {
+        let module_mappings: ModuleMappings =
+            ModuleMappings::from_param_strings(self.module_mapping)?;
+
+        let repository: Repository = Repository::new(
+            self.repository_path.try_into()?,
+            module_mappings,
+            self.skip_folders,
+        );
+        let from: Module = self.from.into();
+        let to: Module = self.to.into();
+
+        let modules = repository.modules();
+
+        for module in [&from, &to] {
+            if !modules.contains(module) {
+                return Err(WhyCommandError::UnknownModule(module.clone()));
+            }
+        }
+
+        let file_dependencies: DependencyList<RepositoryChildPath, RepositoryChildPath> =
+            dependencies_from_analysis(DependencyAnalysis::from(repository), self.strict)?;
+
+        let paths: Vec<(Vec<Module>, Vec<Hop>)> = file_dependencies
+            .to_module_dependencies()
+            .shortest_paths(&from, &to, self.max_paths)
+            .into_iter()
+            .map(|modules| {
+                let hops: Vec<Hop> = modules
+                    .windows(2)
+                    .map(|hop| Hop {
+                        from: hop[0].clone(),
+                        to: hop[1].clone(),
+                        imports: file_dependencies.between_modules(&hop[0], &hop[1]),
+                    })
+                    .collect();
+
+                (modules, hops)
+            })
+            .collect();
+
+        match self.format {
+            OutputFormat::Text => {
+                if paths.is_empty() {
+                    println!("{} does not depend on {}", from, to);
+                }
+
+                for (index, (modules, hops)) in paths.iter().enumerate() {
+                    if index > 0 {
+                        println!();
+                    }
+
+                    println!(
+                        "{}",
+                        modules
+                            .iter()
+                            .map(|module| module.to_string())
+                            .collect::<Vec<String>>()
+                            .join(" > ")
+                    );
+
+                    for hop in hops.iter() {
+                        println!("  {} > {}", hop.from, hop.to);
+
+                        for import in hop.imports.iter() {
+                            println!("    {} > {}", import.located_from(), import.to);
+                        }
+                    }
+                }
+            }
+            OutputFormat::Json => print_json(
+                "why",
+                WhyJson {
+                    from: from.to_string(),
+                    to: to.to_string(),
+                    paths: paths
+                        .iter()
+                        .map(|(modules, hops)| JsonPath {
+                            modules: modules.iter().map(|module| module.to_string()).collect(),
+                            hops: hops
+                                .iter()
+                                .map(|hop| JsonHop {
+                                    from: hop.from.to_string(),
+                                    to: hop.to.to_string(),
+                                    imports: hop
+                                        .imports
+                                        .iter()
+                                        .map(JsonDependency::from_dependency)
+                                        .collect(),
+                                })
+                                .collect(),
+                        })
+                        .collect(),
+                },
+            )?,
+        }
+
+        Ok(())
+    }
+}
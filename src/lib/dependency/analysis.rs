@@ -0,0 +1,61 @@
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+
+use super::list::{
+    DependencyList, DependencyListFromRepositoryAnalyzeFileError, DependencyListFromRepositoryError,
+};
+
+#[derive(Debug)]
+pub struct FileDiagnostic {
+    path: Option<PathBuf>,
+    error: DependencyListFromRepositoryAnalyzeFileError,
+}
+
+impl FileDiagnostic {
+    pub fn new(path: Option<PathBuf>, error: DependencyListFromRepositoryAnalyzeFileError) -> Self {
+        FileDiagnostic { path, error }
+    }
+
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+
+    pub fn error(&self) -> &DependencyListFromRepositoryAnalyzeFileError {
+        &self.error
+    }
+}
+
+impl Display for FileDiagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.path {
+            Some(path) => write!(f, "could not analyze {}", path.display()),
+            None => write!(f, "could not analyze repository entry"),
+        }
+    }
+}
+
+impl std::error::Error for FileDiagnostic {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+#[derive(Debug)]
+pub struct DependencyAnalysis<TFrom: Display, TTo: Display> {
+    pub dependencies: DependencyList<TFrom, TTo>,
+    pub diagnostics: Vec<FileDiagnostic>,
+}
+
+impl<TFrom: Display, TTo: Display> DependencyAnalysis<TFrom, TTo> {
+    pub fn into_strict(
+        self,
+    ) -> Result<DependencyList<TFrom, TTo>, DependencyListFromRepositoryError> {
+        if self.diagnostics.is_empty() {
+            Ok(self.dependencies)
+        } else {
+            Err(DependencyListFromRepositoryError::InvalidFiles(
+                self.diagnostics,
+            ))
+        }
+    }
+}
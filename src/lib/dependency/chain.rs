@@ -37,6 +37,24 @@ impl DependencyChain {
         unique_items.len() < self.0.len()
     }
 
+    pub fn canonical(&self) -> DependencyChain {
+        if !self.is_circular() {
+            return self.clone();
+        }
+
+        let mut members = self.0[..self.0.len() - 1].to_vec();
+        let min_index = members
+            .iter()
+            .enumerate()
+            .min_by_key(|&(_, item)| item)
+            .map(|(i, _)| i)
+            .unwrap_or(0);
+
+        members.rotate_left(min_index);
+        members.push(members[0].clone());
+        DependencyChain(members)
+    }
+
     pub fn extend(&self, next_value: String) -> DependencyChain {
         let mut new_chain = self.0.clone();
         new_chain.push(next_value);
@@ -62,44 +80,160 @@ impl Display for DependencyChain {
     }
 }
 
-pub struct DependencyChainListBuilder {
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DependencyChainLimits {
+    pub max_depth: usize,
+    pub max_results: usize,
+}
+
+impl Default for DependencyChainLimits {
+    fn default() -> Self {
+        DependencyChainLimits {
+            max_depth: 16,
+            max_results: 10_000,
+        }
+    }
+}
+
+/// Enumerates chains breadth first so the shortest ones come first. A chain
+/// ends at a leaf, when it revisits one of its own members, or when it reaches
+/// `max_depth` dependencies. The frontier never grows beyond the remaining
+/// result budget, as every chain in it yields at least one result.
+pub struct DependencyChainEnumerator {
     grouped_dependencies: HashMap<String, Vec<String>>,
+    limits: DependencyChainLimits,
 }
 
-impl DependencyChainListBuilder {
-    pub fn build(grouped_dependencies: HashMap<String, Vec<String>>) -> HashSet<DependencyChain> {
-        let builder = DependencyChainListBuilder {
+impl DependencyChainEnumerator {
+    pub fn new(
+        grouped_dependencies: HashMap<String, Vec<String>>,
+        limits: DependencyChainLimits,
+    ) -> Self {
+        DependencyChainEnumerator {
             grouped_dependencies,
-        };
-
-        builder
-            .grouped_dependencies
-            .par_iter()
-            .flat_map(|(key, dependencies)| {
-                let chain = DependencyChain(vec![key.clone()]);
+            limits,
+        }
+    }
 
-                builder.dfs(chain, dependencies)
-            })
-            .collect()
+    fn is_complete(&self, chain: &DependencyChain) -> bool {
+        chain.has_loop()
+            || chain.0.len() > self.limits.max_depth
+            || self
+                .grouped_dependencies
+                .get(chain.0.last().unwrap())
+                .is_none_or(|dependencies| dependencies.is_empty())
     }
 
-    fn dfs(&self, chain: DependencyChain, dependencies: &Vec<String>) -> HashSet<DependencyChain> {
-        if dependencies.is_empty() || chain.is_circular() || chain.has_loop() {
-            let mut hs = HashSet::new();
+    pub fn enumerate(&self) -> Vec<DependencyChain> {
+        let mut starting_points: Vec<&String> = self.grouped_dependencies.keys().collect();
+        starting_points.sort();
+
+        let mut frontier: Vec<DependencyChain> = starting_points
+            .into_iter()
+            .map(|key| DependencyChain(vec![key.clone()]))
+            .collect();
+        let mut seen: HashSet<DependencyChain> = HashSet::new();
+        let mut chains: Vec<DependencyChain> = vec![];
+
+        while !frontier.is_empty() && chains.len() < self.limits.max_results {
+            let (complete, mut incomplete): (Vec<DependencyChain>, Vec<DependencyChain>) = frontier
+                .into_iter()
+                .partition(|chain| self.is_complete(chain));
+
+            for chain in complete {
+                if chains.len() < self.limits.max_results && seen.insert(chain.clone()) {
+                    chains.push(chain);
+                }
+            }
+
+            incomplete.truncate(self.limits.max_results.saturating_sub(chains.len()));
+
+            frontier = incomplete
+                .par_iter()
+                .flat_map_iter(|chain| {
+                    let mut dependencies = self.grouped_dependencies[chain.0.last().unwrap()]
+                        .iter()
+                        .collect::<Vec<&String>>();
+                    dependencies.sort();
+                    dependencies.dedup();
+
+                    dependencies
+                        .into_iter()
+                        .map(|dependency| chain.extend(dependency.clone()))
+                })
+                .collect();
+        }
 
-            hs.insert(chain);
+        chains
+    }
+}
 
-            return hs;
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn enumerator(
+        edges: &[(&str, &str)],
+        limits: DependencyChainLimits,
+    ) -> DependencyChainEnumerator {
+        let mut grouped_dependencies: HashMap<String, Vec<String>> = HashMap::new();
+
+        for (from, to) in edges {
+            grouped_dependencies
+                .entry(from.to_string())
+                .or_default()
+                .push(to.to_string());
         }
 
-        dependencies
+        DependencyChainEnumerator::new(grouped_dependencies, limits)
+    }
+
+    fn chains(enumerator: &DependencyChainEnumerator) -> Vec<String> {
+        enumerator
+            .enumerate()
             .iter()
-            .flat_map(|dependency| {
-                self.dfs(
-                    chain.extend(dependency.clone()),
-                    self.grouped_dependencies.get(dependency).unwrap_or(&vec![]),
-                )
-            })
-            .collect::<HashSet<DependencyChain>>()
+            .map(|chain| chain.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn enumerate_stops_chains_at_max_depth() {
+        let enumerator = enumerator(
+            &[("a", "b"), ("b", "c"), ("c", "d"), ("d", "e")],
+            DependencyChainLimits {
+                max_depth: 2,
+                max_results: 100,
+            },
+        );
+
+        assert_eq!(
+            chains(&enumerator),
+            vec!["d > e", "a > b > c", "b > c > d", "c > d > e"]
+        );
+    }
+
+    #[test]
+    fn enumerate_returns_at_most_max_results_shortest_first() {
+        let enumerator = enumerator(
+            &[("a", "b"), ("a", "c"), ("a", "d"), ("d", "e")],
+            DependencyChainLimits {
+                max_depth: 16,
+                max_results: 2,
+            },
+        );
+
+        assert_eq!(chains(&enumerator), vec!["a > b", "a > c"]);
+    }
+
+    #[test]
+    fn enumerate_ends_chains_that_revisit_a_member() {
+        let enumerator = enumerator(&[("a", "b"), ("b", "a")], DependencyChainLimits::default());
+
+        assert!(
+            enumerator
+                .enumerate()
+                .iter()
+                .all(|chain| chain.has_loop() && chain.0.len() == 3)
+        );
     }
 }
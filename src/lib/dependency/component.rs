@@ -0,0 +1,251 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DependencyComponent {
+    members: Vec<String>,
+    edges: Vec<(String, String)>,
+}
+
+impl DependencyComponent {
+    pub fn members(&self) -> &[String] {
+        &self.members
+    }
+
+    pub fn edges(&self) -> &[(String, String)] {
+        &self.edges
+    }
+}
+
+impl Display for DependencyComponent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.members.join(", "))
+    }
+}
+
+pub struct ComponentFinder {
+    grouped_dependencies: HashMap<String, Vec<String>>,
+}
+
+impl ComponentFinder {
+    pub fn new(grouped_dependencies: HashMap<String, Vec<String>>) -> Self {
+        ComponentFinder {
+            grouped_dependencies,
+        }
+    }
+
+    fn sorted_graph(&self) -> (Vec<&str>, HashMap<&str, Vec<&str>>) {
+        let mut adjacency: HashMap<&str, Vec<&str>> = HashMap::new();
+
+        for (from, to_list) in self.grouped_dependencies.iter() {
+            adjacency
+                .entry(from.as_str())
+                .or_default()
+                .extend(to_list.iter().map(|to| to.as_str()));
+
+            for to in to_list.iter() {
+                adjacency.entry(to.as_str()).or_default();
+            }
+        }
+
+        for to_list in adjacency.values_mut() {
+            to_list.sort();
+            to_list.dedup();
+        }
+
+        let mut nodes: Vec<&str> = adjacency.keys().copied().collect();
+        nodes.sort();
+
+        (nodes, adjacency)
+    }
+
+    pub fn find_components(&self) -> Vec<Vec<String>> {
+        let (nodes, adjacency) = self.sorted_graph();
+
+        let mut next_index = 0;
+        let mut indices: HashMap<&str, usize> = HashMap::new();
+        let mut low_links: HashMap<&str, usize> = HashMap::new();
+        let mut stack: Vec<&str> = vec![];
+        let mut on_stack: HashSet<&str> = HashSet::new();
+        let mut components: Vec<Vec<String>> = vec![];
+
+        for root in nodes {
+            if indices.contains_key(root) {
+                continue;
+            }
+
+            let mut work: Vec<(&str, usize)> = vec![(root, 0)];
+            indices.insert(root, next_index);
+            low_links.insert(root, next_index);
+            next_index += 1;
+            stack.push(root);
+            on_stack.insert(root);
+
+            while let Some(&(node, next_neighbour)) = work.last() {
+                let neighbours = &adjacency[node];
+
+                if next_neighbour < neighbours.len() {
+                    if let Some(top) = work.last_mut() {
+                        top.1 += 1;
+                    }
+
+                    let neighbour = neighbours[next_neighbour];
+
+                    if !indices.contains_key(neighbour) {
+                        indices.insert(neighbour, next_index);
+                        low_links.insert(neighbour, next_index);
+                        next_index += 1;
+                        stack.push(neighbour);
+                        on_stack.insert(neighbour);
+                        work.push((neighbour, 0));
+                    } else if on_stack.contains(neighbour) {
+                        let low_link = low_links[node].min(indices[neighbour]);
+                        low_links.insert(node, low_link);
+                    }
+
+                    continue;
+                }
+
+                work.pop();
+
+                if let Some(&(parent, _)) = work.last() {
+                    let low_link = low_links[parent].min(low_links[node]);
+                    low_links.insert(parent, low_link);
+                }
+
+                if low_links[node] == indices[node] {
+                    let mut component: Vec<String> = vec![];
+
+                    while let Some(member) = stack.pop() {
+                        on_stack.remove(member);
+                        component.push(member.to_string());
+
+                        if member == node {
+                            break;
+                        }
+                    }
+
+                    component.sort();
+                    components.push(component);
+                }
+            }
+        }
+
+        components
+    }
+
+    pub fn find_tangled_components(&self) -> Vec<DependencyComponent> {
+        let (_, adjacency) = self.sorted_graph();
+
+        let mut tangled: Vec<DependencyComponent> = self
+            .find_components()
+            .into_iter()
+            .filter_map(|members| {
+                let member_set: HashSet<&str> = members.iter().map(|m| m.as_str()).collect();
+                let edges: Vec<(String, String)> = members
+                    .iter()
+                    .flat_map(|from| {
+                        adjacency[from.as_str()]
+                            .iter()
+                            .filter(|to| member_set.contains(*to))
+                            .map(move |to| (from.clone(), to.to_string()))
+                    })
+                    .collect();
+
+                if edges.is_empty() {
+                    None
+                } else {
+                    Some(DependencyComponent { members, edges })
+                }
+            })
+            .collect();
+
+        tangled.sort_by(|a, b| {
+            b.members
+                .len()
+                .cmp(&a.members.len())
+                .then_with(|| a.members.cmp(&b.members))
+        });
+
+        tangled
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn finder(edges: &[(&str, &str)]) -> ComponentFinder {
+        let mut grouped_dependencies: HashMap<String, Vec<String>> = HashMap::new();
+
+        for (from, to) in edges {
+            grouped_dependencies
+                .entry(from.to_string())
+                .or_default()
+                .push(to.to_string());
+        }
+
+        ComponentFinder::new(grouped_dependencies)
+    }
+
+    #[test]
+    fn find_components_groups_strongly_connected_nodes() {
+        let components =
+            finder(&[("a", "b"), ("b", "a"), ("b", "c"), ("c", "d")]).find_components();
+
+        assert_eq!(components.len(), 3);
+        assert!(components.contains(&vec!["a".to_string(), "b".to_string()]));
+        assert!(components.contains(&vec!["c".to_string()]));
+        assert!(components.contains(&vec!["d".to_string()]));
+    }
+
+    #[test]
+    fn find_tangled_components_skips_acyclic_nodes_and_keeps_self_imports() {
+        let tangled = finder(&[("a", "b"), ("b", "c"), ("e", "e")]).find_tangled_components();
+
+        assert_eq!(
+            tangled,
+            vec![DependencyComponent {
+                members: vec!["e".to_string()],
+                edges: vec![("e".to_string(), "e".to_string())],
+            }]
+        );
+    }
+
+    #[test]
+    fn find_tangled_components_orders_largest_first_with_sorted_edges() {
+        let tangled = finder(&[
+            ("x", "y"),
+            ("y", "x"),
+            ("c", "a"),
+            ("a", "b"),
+            ("b", "c"),
+            ("b", "a"),
+        ])
+        .find_tangled_components();
+
+        assert_eq!(
+            tangled,
+            vec![
+                DependencyComponent {
+                    members: vec!["a".to_string(), "b".to_string(), "c".to_string()],
+                    edges: vec![
+                        ("a".to_string(), "b".to_string()),
+                        ("b".to_string(), "a".to_string()),
+                        ("b".to_string(), "c".to_string()),
+                        ("c".to_string(), "a".to_string()),
+                    ],
+                },
+                DependencyComponent {
+                    members: vec!["x".to_string(), "y".to_string()],
+                    edges: vec![
+                        ("x".to_string(), "y".to_string()),
+                        ("y".to_string(), "x".to_string()),
+                    ],
+                },
+            ]
+        );
+    }
+}
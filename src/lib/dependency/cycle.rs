@@ -22,14 +22,27 @@ impl CycleDetector {
         }
     }
 
-    pub fn find_cycles(mut self) -> HashSet<DependencyChain> {
-        let keys: Vec<String> = self.grouped_dependencies.keys().cloned().collect();
+    /// Cycles closed by back-edges of a depth first search that visits nodes
+    /// in sorted order, so the same graph always yields the same cycles. Each
+    /// cycle starts at its smallest member.
+    pub fn find_cycles(mut self) -> Vec<DependencyChain> {
+        let mut keys: Vec<String> = self.grouped_dependencies.keys().cloned().collect();
+        keys.sort();
+
         for node in keys {
             if !self.states.contains_key(&node) {
                 self.dfs(&node, &mut vec![]);
             }
         }
-        self.cycles
+
+        let mut cycles: Vec<DependencyChain> = self
+            .cycles
+            .into_iter()
+            .map(|cycle| cycle.canonical())
+            .collect();
+        cycles.sort_by(|a, b| a.0.cmp(&b.0));
+
+        cycles
     }
 
     fn dfs(&mut self, current_node: &String, path: &mut Vec<String>) {
@@ -37,11 +50,12 @@ impl CycleDetector {
             .insert(current_node.clone(), NodeState::Visiting);
         path.push(current_node.clone());
 
-        let dependencies_to_check = self
+        let mut dependencies_to_check = self
             .grouped_dependencies
             .get(current_node)
             .cloned()
             .unwrap_or_default();
+        dependencies_to_check.sort();
 
         for dependency in dependencies_to_check {
             match self.states.get(&dependency) {
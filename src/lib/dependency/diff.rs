@@ -0,0 +1,188 @@
+use std::{collections::HashMap, fmt::Display, hash::Hash};
+
+use super::{Dependency, chain::DependencyChain, list::DependencyList};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FanChange<T> {
+    pub node: T,
+    pub fan_in: (usize, usize),
+    pub fan_out: (usize, usize),
+}
+
+/// Differences between two dependency graphs of the same repository, each
+/// list sorted for stable output.
+pub struct DependencyListDiff<T: Display> {
+    pub added: Vec<Dependency<T, T>>,
+    pub removed: Vec<Dependency<T, T>>,
+    pub new_cycles: Vec<DependencyChain>,
+    pub fan_changes: Vec<FanChange<T>>,
+}
+
+fn sorted_difference<T: Clone + Display + Eq + Hash>(
+    left: &DependencyList<T, T>,
+    right: &DependencyList<T, T>,
+) -> Vec<Dependency<T, T>> {
+    let mut difference: Vec<Dependency<T, T>> =
+        left.as_ref().difference(right.as_ref()).cloned().collect();
+
+    difference.sort_by_key(|dependency| dependency.to_string());
+    difference
+}
+
+fn fan_counts<T: Clone + Display + Eq + Hash>(
+    dependencies: &DependencyList<T, T>,
+) -> HashMap<T, (usize, usize)> {
+    let mut counts: HashMap<T, (usize, usize)> = HashMap::new();
+
+    for dependency in dependencies.as_ref().iter() {
+        counts.entry(dependency.to.clone()).or_default().0 += 1;
+        counts.entry(dependency.from.clone()).or_default().1 += 1;
+    }
+
+    counts
+}
+
+impl<T: Clone + Display + Eq + Hash + Send + Sync> DependencyListDiff<T> {
+    pub fn between(before: &DependencyList<T, T>, after: &DependencyList<T, T>) -> Self {
+        let added = sorted_difference(after, before);
+        let removed = sorted_difference(before, after);
+
+        let mut new_cycles: Vec<DependencyChain> = vec![];
+
+        for dependency in added.iter() {
+            if let Some(path) = after
+                .shortest_paths(&dependency.to, &dependency.from, 1)
+                .into_iter()
+                .next()
+            {
+                let mut members: Vec<String> = vec![dependency.from.to_string()];
+                members.extend(path.iter().map(|member| member.to_string()));
+
+                let cycle = DependencyChain(members).canonical();
+
+                if !new_cycles.contains(&cycle) {
+                    new_cycles.push(cycle);
+                }
+            }
+        }
+
+        new_cycles.sort_by_key(|cycle| cycle.to_string());
+
+        let before_counts = fan_counts(before);
+        let after_counts = fan_counts(after);
+
+        let mut fan_changes: Vec<FanChange<T>> = before_counts
+            .keys()
+            .chain(after_counts.keys())
+            .filter_map(|node| {
+                let before = before_counts.get(node).copied().unwrap_or_default();
+                let after = after_counts.get(node).copied().unwrap_or_default();
+
+                (before != after).then(|| FanChange {
+                    node: node.clone(),
+                    fan_in: (before.0, after.0),
+                    fan_out: (before.1, after.1),
+                })
+            })
+            .collect();
+
+        fan_changes.sort_by_key(|change| change.node.to_string());
+        fan_changes.dedup_by(|a, b| a.node == b.node);
+
+        DependencyListDiff {
+            added,
+            removed,
+            new_cycles,
+            fan_changes,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    fn list(edges: &[(&str, &str)]) -> DependencyList<String, String> {
+        edges
+            .iter()
+            .map(|(from, to)| Dependency::create(from.to_string(), to.to_string()))
+            .collect::<HashSet<Dependency<String, String>>>()
+            .into()
+    }
+
+    fn names(dependencies: &[Dependency<String, String>]) -> Vec<String> {
+        dependencies
+            .iter()
+            .map(|dependency| dependency.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn between_lists_added_and_removed_dependencies_in_order() {
+        let diff = DependencyListDiff::between(
+            &list(&[("a", "b"), ("b", "c")]),
+            &list(&[("b", "c"), ("c", "e"), ("a", "d")]),
+        );
+
+        assert_eq!(names(&diff.added), vec!["a > d", "c > e"]);
+        assert_eq!(names(&diff.removed), vec!["a > b"]);
+        assert!(diff.new_cycles.is_empty());
+        assert!(!diff.is_empty());
+    }
+
+    #[test]
+    fn between_reports_cycles_closed_by_added_dependencies_once() {
+        let diff = DependencyListDiff::between(
+            &list(&[("a", "b"), ("b", "c")]),
+            &list(&[("a", "b"), ("b", "c"), ("c", "a")]),
+        );
+
+        assert_eq!(
+            diff.new_cycles
+                .iter()
+                .map(|cycle| cycle.to_string())
+                .collect::<Vec<String>>(),
+            vec!["a > b > c > a"]
+        );
+    }
+
+    #[test]
+    fn between_reports_fan_changes_of_touched_nodes_only() {
+        let diff = DependencyListDiff::between(
+            &list(&[("a", "b"), ("c", "d")]),
+            &list(&[("a", "b"), ("c", "d"), ("a", "d")]),
+        );
+
+        assert_eq!(
+            diff.fan_changes,
+            vec![
+                FanChange {
+                    node: "a".to_string(),
+                    fan_in: (0, 0),
+                    fan_out: (1, 2),
+                },
+                FanChange {
+                    node: "d".to_string(),
+                    fan_in: (1, 2),
+                    fan_out: (0, 0),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn between_identical_lists_is_empty() {
+        let dependencies = list(&[("a", "b"), ("b", "a")]);
+        let diff = DependencyListDiff::between(&dependencies, &dependencies);
+
+        assert!(diff.is_empty());
+        assert!(diff.new_cycles.is_empty());
+        assert!(diff.fan_changes.is_empty());
+    }
+}
@@ -0,0 +1,275 @@
+use std::collections::{HashMap, HashSet};
+
+use super::component::ComponentFinder;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FeedbackArc {
+    from: String,
+    to: String,
+    weight: usize,
+}
+
+impl FeedbackArc {
+    pub fn from(&self) -> &str {
+        &self.from
+    }
+
+    pub fn to(&self) -> &str {
+        &self.to
+    }
+
+    pub fn weight(&self) -> usize {
+        self.weight
+    }
+}
+
+/// Suggests a small, cheap set of edges whose removal leaves the graph
+/// acyclic. Uses the greedy Eades-Lin-Smyth ordering per strongly connected
+/// component, then puts back every removed edge that no longer closes a cycle.
+pub struct FeedbackArcSetFinder {
+    weighted_dependencies: HashMap<String, HashMap<String, usize>>,
+}
+
+impl FeedbackArcSetFinder {
+    pub fn new(weighted_dependencies: HashMap<String, HashMap<String, usize>>) -> Self {
+        FeedbackArcSetFinder {
+            weighted_dependencies,
+        }
+    }
+
+    fn weight(&self, from: &str, to: &str) -> usize {
+        self.weighted_dependencies
+            .get(from)
+            .and_then(|to_weights| to_weights.get(to))
+            .copied()
+            .unwrap_or(1)
+    }
+
+    fn order_component(&self, edges: &[(String, String)]) -> HashMap<String, usize> {
+        let mut outgoing: HashMap<&str, HashSet<&str>> = HashMap::new();
+        let mut incoming: HashMap<&str, HashSet<&str>> = HashMap::new();
+
+        for (from, to) in edges.iter() {
+            if from != to {
+                outgoing.entry(from).or_default().insert(to);
+                incoming.entry(to).or_default().insert(from);
+            }
+
+            outgoing.entry(from).or_default();
+            incoming.entry(from).or_default();
+            outgoing.entry(to).or_default();
+            incoming.entry(to).or_default();
+        }
+
+        let mut remaining: Vec<&str> = outgoing.keys().copied().collect();
+        remaining.sort();
+
+        let mut head: Vec<&str> = vec![];
+        let mut tail: Vec<&str> = vec![];
+
+        while !remaining.is_empty() {
+            let next = remaining
+                .iter()
+                .copied()
+                .find(|node| outgoing[node].is_empty())
+                .map(|sink| (sink, false))
+                .or_else(|| {
+                    remaining
+                        .iter()
+                        .copied()
+                        .find(|node| incoming[node].is_empty())
+                        .map(|source| (source, true))
+                })
+                .unwrap_or_else(|| {
+                    let delta = |node: &&str| -> i64 {
+                        let out_weight: usize =
+                            outgoing[node].iter().map(|to| self.weight(node, to)).sum();
+                        let in_weight: usize = incoming[node]
+                            .iter()
+                            .map(|from| self.weight(from, node))
+                            .sum();
+
+                        out_weight as i64 - in_weight as i64
+                    };
+                    let mut best = remaining[0];
+
+                    for node in remaining.iter().skip(1) {
+                        if delta(node) > delta(&best) {
+                            best = node;
+                        }
+                    }
+
+                    (best, true)
+                });
+
+            let (node, to_head) = next;
+
+            if to_head {
+                head.push(node);
+            } else {
+                tail.push(node);
+            }
+
+            remaining.retain(|remaining_node| *remaining_node != node);
+
+            for to in outgoing.remove(node).unwrap_or_default() {
+                if let Some(from_set) = incoming.get_mut(to) {
+                    from_set.remove(node);
+                }
+            }
+
+            for from in incoming.remove(node).unwrap_or_default() {
+                if let Some(to_set) = outgoing.get_mut(from) {
+                    to_set.remove(node);
+                }
+            }
+
+            outgoing.insert(node, HashSet::new());
+            incoming.insert(node, HashSet::new());
+        }
+
+        head.into_iter()
+            .chain(tail.into_iter().rev())
+            .enumerate()
+            .map(|(position, node)| (node.to_string(), position))
+            .collect()
+    }
+
+    fn has_path(kept: &HashSet<(String, String)>, from: &str, to: &str) -> bool {
+        let mut visited: HashSet<&str> = HashSet::new();
+        let mut stack: Vec<&str> = vec![from];
+
+        while let Some(node) = stack.pop() {
+            if node == to {
+                return true;
+            }
+
+            if !visited.insert(node) {
+                continue;
+            }
+
+            stack.extend(
+                kept.iter()
+                    .filter(|(edge_from, _)| edge_from == node)
+                    .map(|(_, edge_to)| edge_to.as_str()),
+            );
+        }
+
+        false
+    }
+
+    pub fn find_feedback_arcs(&self) -> Vec<FeedbackArc> {
+        let grouped_dependencies: HashMap<String, Vec<String>> = self
+            .weighted_dependencies
+            .iter()
+            .map(|(from, to_weights)| (from.clone(), to_weights.keys().cloned().collect()))
+            .collect();
+
+        let mut arcs: Vec<FeedbackArc> = ComponentFinder::new(grouped_dependencies)
+            .find_tangled_components()
+            .into_iter()
+            .flat_map(|component| {
+                let positions = self.order_component(component.edges());
+
+                let mut removed: Vec<(String, String)> = component
+                    .edges()
+                    .iter()
+                    .filter(|(from, to)| positions[to] <= positions[from])
+                    .cloned()
+                    .collect();
+                let mut kept: HashSet<(String, String)> = component
+                    .edges()
+                    .iter()
+                    .filter(|edge| !removed.contains(edge))
+                    .cloned()
+                    .collect();
+
+                removed.sort_by(|(a_from, a_to), (b_from, b_to)| {
+                    self.weight(b_from, b_to)
+                        .cmp(&self.weight(a_from, a_to))
+                        .then_with(|| (a_from, a_to).cmp(&(b_from, b_to)))
+                });
+                removed.retain(|(from, to)| {
+                    if from != to && !Self::has_path(&kept, to, from) {
+                        kept.insert((from.clone(), to.clone()));
+                        false
+                    } else {
+                        true
+                    }
+                });
+
+                removed
+            })
+            .map(|(from, to)| FeedbackArc {
+                weight: self.weight(&from, &to),
+                from,
+                to,
+            })
+            .collect();
+
+        arcs.sort_by(|a, b| {
+            a.weight
+                .cmp(&b.weight)
+                .then_with(|| (&a.from, &a.to).cmp(&(&b.from, &b.to)))
+        });
+
+        arcs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn arcs(edges: &[(&str, &str, usize)]) -> Vec<(String, String, usize)> {
+        let mut weighted_dependencies: HashMap<String, HashMap<String, usize>> = HashMap::new();
+
+        for (from, to, weight) in edges {
+            weighted_dependencies
+                .entry(from.to_string())
+                .or_default()
+                .insert(to.to_string(), *weight);
+        }
+
+        FeedbackArcSetFinder::new(weighted_dependencies)
+            .find_feedback_arcs()
+            .into_iter()
+            .map(|arc| (arc.from().to_string(), arc.to().to_string(), arc.weight()))
+            .collect()
+    }
+
+    #[test]
+    fn cuts_the_lighter_edge_of_a_two_cycle() {
+        assert_eq!(
+            arcs(&[("a", "b", 5), ("b", "a", 1)]),
+            vec![("b".to_string(), "a".to_string(), 1)]
+        );
+        assert_eq!(
+            arcs(&[("a", "b", 1), ("b", "a", 5)]),
+            vec![("a".to_string(), "b".to_string(), 1)]
+        );
+    }
+
+    #[test]
+    fn suggests_nothing_for_an_acyclic_graph() {
+        assert!(arcs(&[("a", "b", 1), ("b", "c", 2), ("a", "c", 3)]).is_empty());
+    }
+
+    #[test]
+    fn puts_back_removed_edges_that_close_no_cycle() {
+        // The ordering is a, b, c, d, which points both c > a and d > c
+        // backwards. Once d > c is gone nothing leads from a back to c, so
+        // c > a is put back.
+        assert_eq!(
+            arcs(&[
+                ("a", "b", 1),
+                ("a", "d", 1),
+                ("b", "d", 1),
+                ("c", "a", 1),
+                ("c", "d", 1),
+                ("d", "c", 1),
+            ]),
+            vec![("d".to_string(), "c".to_string(), 1)]
+        );
+    }
+}
@@ -0,0 +1,151 @@
+use std::collections::HashMap;
+
+use super::component::ComponentFinder;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LeveledComponent {
+    members: Vec<String>,
+    level: usize,
+    circular: bool,
+}
+
+impl LeveledComponent {
+    pub fn members(&self) -> &[String] {
+        &self.members
+    }
+
+    /// Length of the longest dependency chain below this component; nodes
+    /// without dependencies are on level 0.
+    pub fn level(&self) -> usize {
+        self.level
+    }
+
+    pub fn is_circular(&self) -> bool {
+        self.circular
+    }
+}
+
+/// Orders strongly connected components so every component comes after all of
+/// the components it depends on.
+pub struct LevelFinder {
+    grouped_dependencies: HashMap<String, Vec<String>>,
+}
+
+impl LevelFinder {
+    pub fn new(grouped_dependencies: HashMap<String, Vec<String>>) -> Self {
+        LevelFinder {
+            grouped_dependencies,
+        }
+    }
+
+    pub fn find_levels(&self) -> Vec<LeveledComponent> {
+        let components = ComponentFinder::new(self.grouped_dependencies.clone()).find_components();
+        let component_of: HashMap<&str, usize> = components
+            .iter()
+            .enumerate()
+            .flat_map(|(index, members)| members.iter().map(move |m| (m.as_str(), index)))
+            .collect();
+
+        // Tarjan completes a component only after everything it reaches, so
+        // dependencies always have a lower index than their dependants.
+        let mut levels: Vec<usize> = vec![0; components.len()];
+        let mut circular: Vec<bool> = vec![false; components.len()];
+
+        for (index, members) in components.iter().enumerate() {
+            for member in members.iter() {
+                for dependency in self.grouped_dependencies.get(member).into_iter().flatten() {
+                    let dependency_index = component_of[dependency.as_str()];
+
+                    if dependency_index == index {
+                        circular[index] = true;
+                    } else {
+                        levels[index] = levels[index].max(levels[dependency_index] + 1);
+                    }
+                }
+            }
+        }
+
+        let mut leveled: Vec<LeveledComponent> = components
+            .into_iter()
+            .enumerate()
+            .map(|(index, members)| LeveledComponent {
+                members,
+                level: levels[index],
+                circular: circular[index],
+            })
+            .collect();
+
+        leveled.sort_by(|a, b| {
+            a.level
+                .cmp(&b.level)
+                .then_with(|| a.members.cmp(&b.members))
+        });
+
+        leveled
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn levels(edges: &[(&str, &str)], isolated: &[&str]) -> Vec<(Vec<String>, usize, bool)> {
+        let mut grouped_dependencies: HashMap<String, Vec<String>> = HashMap::new();
+
+        for (from, to) in edges {
+            grouped_dependencies
+                .entry(from.to_string())
+                .or_default()
+                .push(to.to_string());
+        }
+
+        for node in isolated {
+            grouped_dependencies.entry(node.to_string()).or_default();
+        }
+
+        LevelFinder::new(grouped_dependencies)
+            .find_levels()
+            .into_iter()
+            .map(|component| {
+                (
+                    component.members().to_vec(),
+                    component.level(),
+                    component.is_circular(),
+                )
+            })
+            .collect()
+    }
+
+    fn members(names: &[&str]) -> Vec<String> {
+        names.iter().map(|name| name.to_string()).collect()
+    }
+
+    #[test]
+    fn find_levels_uses_the_longest_chain_below_each_node() {
+        assert_eq!(
+            levels(&[("app", "ui"), ("app", "core"), ("ui", "core")], &["docs"]),
+            vec![
+                (members(&["core"]), 0, false),
+                (members(&["docs"]), 0, false),
+                (members(&["ui"]), 1, false),
+                (members(&["app"]), 2, false),
+            ]
+        );
+    }
+
+    #[test]
+    fn find_levels_places_cycles_as_one_circular_component() {
+        assert_eq!(
+            levels(
+                &[("a", "b"), ("b", "a"), ("b", "c"), ("d", "a"), ("e", "e")],
+                &[]
+            ),
+            vec![
+                (members(&["c"]), 0, false),
+                (members(&["e"]), 0, true),
+                (members(&["a", "b"]), 1, true),
+                (members(&["d"]), 2, false),
+            ]
+        );
+    }
+}
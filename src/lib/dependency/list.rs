@@ -2,12 +2,22 @@ use rayon::{iter::Either, prelude::*};
 use std::collections::{HashMap, HashSet};
 use std::fmt::Display;
 use std::hash::Hash;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
 
 use crate::{
-    dependency::{Dependency, chain::DependencyChain},
+    dependency::{
+        Dependency,
+        analysis::{DependencyAnalysis, FileDiagnostic},
+        chain::{DependencyChain, DependencyChainEnumerator, DependencyChainLimits},
+        component::{ComponentFinder, DependencyComponent},
+        feedback::FeedbackArcSetFinder,
+        level::{LevelFinder, LeveledComponent},
+    },
+    import_path::ImportKind,
     module::Module,
     repository::{
-        Repository, RepositoryFilesError,
+        Repository, RepositoryFiles, RepositoryFilesError,
         child::{
             RepositoryChildPath, RepositoryChildPathFromImportPathError,
             RepositoryChildPathFromPathError, RepositoryChildPathFromRepositoryFileError,
@@ -17,8 +27,6 @@ use crate::{
     },
 };
 
-use super::chain::DependencyChainListBuilder;
-
 #[derive(Debug)]
 pub struct DependencyList<TFrom: Display, TTo: Display>(HashSet<Dependency<TFrom, TTo>>);
 
@@ -65,14 +73,36 @@ impl<TFrom: Display + Clone + Eq + Hash, TTo: Display + Clone + Eq + Hash>
         }
         map
     }
+
+    pub fn group_by_to(&self) -> HashMap<TTo, Vec<TFrom>> {
+        let mut map: HashMap<TTo, Vec<TFrom>> = HashMap::new();
+        for dep in self.0.iter() {
+            map.entry(dep.to.clone())
+                .or_default()
+                .push(dep.from.clone());
+        }
+        map
+    }
+
+    pub fn reversed(&self) -> DependencyList<TTo, TFrom> {
+        DependencyList(
+            self.0
+                .iter()
+                .map(|dependency| Dependency {
+                    from: dependency.to.clone(),
+                    to: dependency.from.clone(),
+                    location: dependency.location.clone(),
+                })
+                .collect(),
+        )
+    }
 }
 
 impl<TDependencyType: Clone + Display + Eq + Hash + Send + Sync>
     DependencyList<TDependencyType, TDependencyType>
 {
-    pub fn to_dependency_chain_list(&self) -> HashSet<DependencyChain> {
-        let grouped_by_from = self.group_by_from();
-        let string_grouped_dependencies: HashMap<String, Vec<String>> = grouped_by_from
+    pub fn group_by_from_string(&self) -> HashMap<String, Vec<String>> {
+        self.group_by_from()
             .into_iter()
             .map(|(from, to_list)| {
                 (
@@ -80,9 +110,283 @@ impl<TDependencyType: Clone + Display + Eq + Hash + Send + Sync>
                     to_list.into_iter().map(|to| to.to_string()).collect(),
                 )
             })
+            .collect()
+    }
+
+    pub fn to_dependency_chain_list(&self, limits: DependencyChainLimits) -> Vec<DependencyChain> {
+        DependencyChainEnumerator::new(self.group_by_from_string(), limits).enumerate()
+    }
+
+    pub fn to_tangled_components(&self) -> Vec<DependencyComponent> {
+        ComponentFinder::new(self.group_by_from_string()).find_tangled_components()
+    }
+
+    /// Components in dependency order with their level, `nodes` adds nodes that
+    /// have no dependencies in either direction.
+    pub fn to_levels<TIterator: IntoIterator<Item = TDependencyType>>(
+        &self,
+        nodes: TIterator,
+    ) -> Vec<LeveledComponent> {
+        let mut grouped_dependencies = self.group_by_from_string();
+
+        for node in nodes {
+            grouped_dependencies.entry(node.to_string()).or_default();
+        }
+
+        LevelFinder::new(grouped_dependencies).find_levels()
+    }
+
+    /// Edges to remove so no cycles remain, cheapest first. Edges missing from
+    /// `weights` count as a single import.
+    pub fn to_feedback_arc_set(
+        &self,
+        weights: &HashMap<Dependency<TDependencyType, TDependencyType>, usize>,
+    ) -> Vec<(Dependency<TDependencyType, TDependencyType>, usize)> {
+        let mut weighted_dependencies: HashMap<String, HashMap<String, usize>> = HashMap::new();
+        let mut dependencies_by_edge: HashMap<
+            (String, String),
+            &Dependency<TDependencyType, TDependencyType>,
+        > = HashMap::new();
+
+        for dependency in self.0.iter() {
+            let from = dependency.from.to_string();
+            let to = dependency.to.to_string();
+
+            weighted_dependencies
+                .entry(from.clone())
+                .or_default()
+                .insert(to.clone(), weights.get(dependency).copied().unwrap_or(1));
+            dependencies_by_edge.insert((from, to), dependency);
+        }
+
+        FeedbackArcSetFinder::new(weighted_dependencies)
+            .find_feedback_arcs()
+            .into_iter()
+            .filter_map(|arc| {
+                dependencies_by_edge
+                    .get(&(arc.from().to_string(), arc.to().to_string()))
+                    .map(|dependency| (Dependency::clone(dependency), arc.weight()))
+            })
+            .collect()
+    }
+
+    pub fn reachable_from<TIterator: IntoIterator<Item = TDependencyType>>(
+        &self,
+        starting_points: TIterator,
+    ) -> HashSet<TDependencyType> {
+        let grouped_by_from = self.group_by_from();
+        let mut reachable: HashSet<TDependencyType> = HashSet::new();
+        let mut to_visit: Vec<TDependencyType> = starting_points.into_iter().collect();
+
+        while let Some(current) = to_visit.pop() {
+            if !reachable.insert(current.clone()) {
+                continue;
+            }
+
+            if let Some(dependencies) = grouped_by_from.get(&current) {
+                to_visit.extend(
+                    dependencies
+                        .iter()
+                        .filter(|dependency| !reachable.contains(dependency))
+                        .cloned(),
+                );
+            }
+        }
+
+        reachable
+    }
+
+    /// Everything reachable from `starting_point` in at most `max_depth` steps,
+    /// with the number of steps needed, closest first.
+    pub fn reachable_within(
+        &self,
+        starting_point: &TDependencyType,
+        max_depth: Option<usize>,
+    ) -> Vec<(TDependencyType, usize)> {
+        let grouped_by_from = self.group_by_from();
+        let mut distances: HashMap<TDependencyType, usize> =
+            HashMap::from([(starting_point.clone(), 0)]);
+        let mut frontier: Vec<TDependencyType> = vec![starting_point.clone()];
+        let mut depth = 0;
+
+        while !frontier.is_empty() && max_depth.is_none_or(|max_depth| depth < max_depth) {
+            depth += 1;
+
+            frontier = frontier
+                .iter()
+                .flat_map(|current| grouped_by_from.get(current).into_iter().flatten())
+                .filter(|dependency| !distances.contains_key(*dependency))
+                .cloned()
+                .collect::<HashSet<TDependencyType>>()
+                .into_iter()
+                .collect();
+
+            for dependency in frontier.iter() {
+                distances.insert(dependency.clone(), depth);
+            }
+        }
+
+        distances.remove(starting_point);
+
+        let mut reachable: Vec<(TDependencyType, usize)> = distances.into_iter().collect();
+        reachable.sort_by(|(a, a_distance), (b, b_distance)| {
+            a_distance
+                .cmp(b_distance)
+                .then_with(|| a.to_string().cmp(&b.to_string()))
+        });
+
+        reachable
+    }
+
+    /// Every shortest path from `from` to `to`, at most `max_paths` of them,
+    /// ordered by their members.
+    pub fn shortest_paths(
+        &self,
+        from: &TDependencyType,
+        to: &TDependencyType,
+        max_paths: usize,
+    ) -> Vec<Vec<TDependencyType>> {
+        let grouped_by_from = self.group_by_from();
+        let mut predecessors: HashMap<TDependencyType, Vec<TDependencyType>> = HashMap::new();
+        let mut distances: HashMap<TDependencyType, usize> = HashMap::from([(from.clone(), 0)]);
+        let mut frontier: Vec<TDependencyType> = vec![from.clone()];
+
+        while !frontier.is_empty() && !distances.contains_key(to) {
+            let mut next_frontier: Vec<TDependencyType> = vec![];
+
+            for current in frontier.iter() {
+                let distance = distances[current] + 1;
+
+                for dependency in grouped_by_from.get(current).into_iter().flatten() {
+                    match distances.get(dependency) {
+                        None => {
+                            distances.insert(dependency.clone(), distance);
+                            next_frontier.push(dependency.clone());
+                        }
+                        Some(known) if *known != distance => continue,
+                        Some(_) => (),
+                    }
+
+                    predecessors
+                        .entry(dependency.clone())
+                        .or_default()
+                        .push(current.clone());
+                }
+            }
+
+            frontier = next_frontier;
+        }
+
+        if from == to || !distances.contains_key(to) {
+            return vec![];
+        }
+
+        // Walk forward over the imports that lie on a shortest path, taking
+        // members in order, so a limited search still yields the first paths
+        let mut on_path: HashSet<TDependencyType> = HashSet::from([to.clone()]);
+        let mut pending: Vec<TDependencyType> = vec![to.clone()];
+
+        while let Some(node) = pending.pop() {
+            for predecessor in predecessors.get(&node).into_iter().flatten() {
+                if on_path.insert(predecessor.clone()) {
+                    pending.push(predecessor.clone());
+                }
+            }
+        }
+
+        let mut successors: HashMap<TDependencyType, Vec<TDependencyType>> = HashMap::new();
+
+        for (node, node_predecessors) in predecessors
+            .iter()
+            .filter(|(node, _)| on_path.contains(*node))
+        {
+            for predecessor in node_predecessors {
+                successors
+                    .entry(predecessor.clone())
+                    .or_default()
+                    .push(node.clone());
+            }
+        }
+
+        for node_successors in successors.values_mut() {
+            node_successors.sort_by_key(|node| node.to_string());
+        }
+
+        let mut paths: Vec<Vec<TDependencyType>> = vec![];
+        let mut stack: Vec<Vec<TDependencyType>> = vec![vec![from.clone()]];
+
+        while let Some(path) = stack.pop() {
+            if paths.len() >= max_paths {
+                break;
+            }
+
+            let last = path.last().unwrap();
+
+            if last == to {
+                paths.push(path);
+                continue;
+            }
+
+            for next in successors.get(last).into_iter().flatten().rev() {
+                let mut extended = path.clone();
+                extended.push(next.clone());
+                stack.push(extended);
+            }
+        }
+
+        paths
+    }
+}
+
+impl DependencyList<RepositoryChildPath, RepositoryChildPath> {
+    /// Number of file level imports behind each dependency between two modules.
+    pub fn count_module_imports(&self) -> HashMap<Dependency<Module, Module>, usize> {
+        let mut counts: HashMap<Dependency<Module, Module>, usize> = HashMap::new();
+
+        for dependency in self.0.iter() {
+            if let (Ok(from), Ok(to)) = (dependency.from.module(), dependency.to.module()) {
+                let module_dependency = Dependency::create(from, to);
+
+                if !module_dependency.is_internal() {
+                    *counts.entry(module_dependency).or_default() += 1;
+                }
+            }
+        }
+
+        counts
+    }
+
+    pub fn to_module_dependencies(&self) -> DependencyList<Module, Module> {
+        self.count_module_imports()
+            .into_keys()
+            .collect::<HashSet<Dependency<Module, Module>>>()
+            .into()
+    }
+
+    /// The file imports that make module `from` depend on module `to`.
+    pub fn between_modules(
+        &self,
+        from: &Module,
+        to: &Module,
+    ) -> Vec<Dependency<RepositoryChildPath, RepositoryChildPath>> {
+        let mut dependencies: Vec<Dependency<RepositoryChildPath, RepositoryChildPath>> = self
+            .0
+            .iter()
+            .filter(|dependency| {
+                dependency.from.module().is_ok_and(|module| &module == from)
+                    && dependency.to.module().is_ok_and(|module| &module == to)
+            })
+            .cloned()
             .collect();
 
-        DependencyChainListBuilder::build(string_grouped_dependencies)
+        dependencies.sort_by(|a, b| {
+            a.from
+                .to_string()
+                .cmp(&b.from.to_string())
+                .then_with(|| a.to.to_string().cmp(&b.to.to_string()))
+        });
+
+        dependencies
     }
 }
 
@@ -94,6 +398,40 @@ pub enum DependencyListFromRepositoryFileError {
     CouldNotLocateFileWithinRepository(RepositoryChildPathFromRepositoryFileError),
 }
 
+impl Display for DependencyListFromRepositoryFileError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DependencyListFromRepositoryFileError::InvalidImports(errors) => write!(
+                f,
+                "{} import(s) could not be resolved within the repository",
+                errors.len()
+            ),
+            DependencyListFromRepositoryFileError::CouldNotScanFile(_) => {
+                write!(f, "could not scan file")
+            }
+            DependencyListFromRepositoryFileError::CouldNotReadImports(_) => {
+                write!(f, "could not read imports")
+            }
+            DependencyListFromRepositoryFileError::CouldNotLocateFileWithinRepository(_) => {
+                write!(f, "could not locate file within repository")
+            }
+        }
+    }
+}
+
+impl std::error::Error for DependencyListFromRepositoryFileError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            DependencyListFromRepositoryFileError::InvalidImports(errors) => errors
+                .first()
+                .map(|e| e as &(dyn std::error::Error + 'static)),
+            DependencyListFromRepositoryFileError::CouldNotScanFile(e) => Some(e),
+            DependencyListFromRepositoryFileError::CouldNotReadImports(e) => Some(e),
+            DependencyListFromRepositoryFileError::CouldNotLocateFileWithinRepository(e) => Some(e),
+        }
+    }
+}
+
 impl From<RepositoryFileResolveImportsError> for DependencyListFromRepositoryFileError {
     fn from(value: RepositoryFileResolveImportsError) -> Self {
         DependencyListFromRepositoryFileError::CouldNotReadImports(value)
@@ -106,11 +444,22 @@ impl From<RepositoryChildPathFromRepositoryFileError> for DependencyListFromRepo
     }
 }
 
-impl TryFrom<RepositoryFile> for DependencyList<RepositoryChildPath, RepositoryChildPath> {
+impl TryFrom<&RepositoryFile> for DependencyList<RepositoryChildPath, RepositoryChildPath> {
     type Error = DependencyListFromRepositoryFileError;
 
-    fn try_from(analyzed_file: RepositoryFile) -> Result<Self, Self::Error> {
-        let repository_child_path = RepositoryChildPath::from_repository_file(&analyzed_file)?;
+    fn try_from(analyzed_file: &RepositoryFile) -> Result<Self, Self::Error> {
+        DependencyList::from_repository_file(analyzed_file, &[ImportKind::Import])
+    }
+}
+
+impl DependencyList<RepositoryChildPath, RepositoryChildPath> {
+    /// Dependencies of a single file through the given kinds of import
+    /// statements.
+    pub fn from_repository_file(
+        analyzed_file: &RepositoryFile,
+        kinds: &[ImportKind],
+    ) -> Result<Self, DependencyListFromRepositoryFileError> {
+        let repository_child_path = RepositoryChildPath::from_repository_file(analyzed_file)?;
         let (dependencies, errors): (
             Vec<
                 Result<
@@ -126,15 +475,20 @@ impl TryFrom<RepositoryFile> for DependencyList<RepositoryChildPath, RepositoryC
             >,
         ) = analyzed_file
             .imports()?
-            .into_iter()
+            .iter()
+            .filter(|import_path| kinds.contains(&import_path.kind()))
             .map(
                 |import_path| -> Result<
                     Dependency<RepositoryChildPath, RepositoryChildPath>,
                     RepositoryChildPathFromImportPathError,
                 > {
-                    RepositoryChildPath::from_import_path(import_path, &analyzed_file).map(
+                    RepositoryChildPath::from_import_path(import_path, analyzed_file).map(
                         |imported_file| {
-                            Dependency::create(repository_child_path.clone(), imported_file)
+                            Dependency::create_at(
+                                repository_child_path.clone(),
+                                imported_file,
+                                import_path.location().clone(),
+                            )
                         },
                     )
                 },
@@ -163,19 +517,71 @@ impl TryFrom<RepositoryFile> for DependencyList<RepositoryChildPath, RepositoryC
 
 #[derive(Debug)]
 pub enum DependencyListFromRepositoryError {
-    InvalidFiles(Vec<DependencyListFromRepositoryAnalyzeFileError>),
+    InvalidFiles(Vec<FileDiagnostic>),
+}
+
+impl Display for DependencyListFromRepositoryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DependencyListFromRepositoryError::InvalidFiles(errors) => {
+                write!(f, "{} file(s) could not be analyzed", errors.len())
+            }
+        }
+    }
+}
+
+impl std::error::Error for DependencyListFromRepositoryError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            DependencyListFromRepositoryError::InvalidFiles(errors) => errors
+                .first()
+                .map(|e| e as &(dyn std::error::Error + 'static)),
+        }
+    }
 }
 
 #[derive(Debug)]
 pub enum DependencyListFromRepositoryAnalyzeFileError {
-    CouldNotScanFile(RepositoryFilesError),
+    CouldNotScanFile(Arc<RepositoryFilesError>),
     CouldNotGetModule(RepositoryFileModuleError),
     CouldNotConvertFilePathToModule(RepositoryChildPathModuleError),
     CouldNotGetDependencyList(DependencyListFromRepositoryFileError),
 }
 
-impl From<RepositoryFilesError> for DependencyListFromRepositoryAnalyzeFileError {
-    fn from(value: RepositoryFilesError) -> Self {
+impl Display for DependencyListFromRepositoryAnalyzeFileError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DependencyListFromRepositoryAnalyzeFileError::CouldNotScanFile(_) => {
+                write!(f, "could not scan file")
+            }
+            DependencyListFromRepositoryAnalyzeFileError::CouldNotGetModule(_) => {
+                write!(f, "could not determine the module of a file")
+            }
+            DependencyListFromRepositoryAnalyzeFileError::CouldNotConvertFilePathToModule(_) => {
+                write!(f, "could not determine the module of an imported file")
+            }
+            DependencyListFromRepositoryAnalyzeFileError::CouldNotGetDependencyList(_) => {
+                write!(f, "could not collect the dependencies of a file")
+            }
+        }
+    }
+}
+
+impl std::error::Error for DependencyListFromRepositoryAnalyzeFileError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            DependencyListFromRepositoryAnalyzeFileError::CouldNotScanFile(e) => Some(e),
+            DependencyListFromRepositoryAnalyzeFileError::CouldNotGetModule(e) => Some(e),
+            DependencyListFromRepositoryAnalyzeFileError::CouldNotConvertFilePathToModule(e) => {
+                Some(e)
+            }
+            DependencyListFromRepositoryAnalyzeFileError::CouldNotGetDependencyList(e) => Some(e),
+        }
+    }
+}
+
+impl From<Arc<RepositoryFilesError>> for DependencyListFromRepositoryAnalyzeFileError {
+    fn from(value: Arc<RepositoryFilesError>) -> Self {
         DependencyListFromRepositoryAnalyzeFileError::CouldNotScanFile(value)
     }
 }
@@ -198,160 +604,186 @@ impl From<DependencyListFromRepositoryFileError> for DependencyListFromRepositor
     }
 }
 
-impl TryFrom<Repository> for DependencyList<Module, Module> {
-    type Error = DependencyListFromRepositoryError;
-
-    fn try_from(repository: Repository) -> Result<Self, Self::Error> {
-        let (dependencies, errors): (
-            Vec<Vec<Dependency<Module, Module>>>,
-            Vec<DependencyListFromRepositoryAnalyzeFileError>,
-        ) = repository
-            .files()
-            .map(
-                |analyzed_file_result| -> Result<
-                    Vec<Dependency<Module, Module>>,
-                    DependencyListFromRepositoryAnalyzeFileError,
-                > {
-                    let analyzed_file = match analyzed_file_result {
-                        Ok(file) => file,
-                        Err(e) => match e {
-                            RepositoryFilesError::CannotAnalyzeFile(_) => return Ok(vec![]),
-                            _ => return Err(e.into()),
-                        },
-                    };
-
-                    let dependencies: DependencyList<RepositoryChildPath, RepositoryChildPath> =
-                        match analyzed_file.try_into() {
-                            Ok(d) => d,
-                            Err(e) => return Err(e.into()),
-                        };
-
-                    dependencies
-                        .as_ref()
-                        .iter()
-                        .map(
-                            |d| -> Result<
-                                Dependency<Module, Module>,
-                                DependencyListFromRepositoryAnalyzeFileError,
-                            > {
-                                let (from_result, to_result) = (d.from.module(), d.to.module());
-
-                                let from = match from_result {
-                                    Ok(from_module) => from_module,
-                                    Err(e) => return Err(e.into()),
-                                };
-
-                                let to = match to_result {
-                                    Ok(to_module) => to_module,
-                                    Err(e) => return Err(e.into()),
-                                };
-
-                                Ok(Dependency::create(from, to))
-                            },
-                        )
-                        .filter(|dependency_result| match dependency_result {
-                            Ok(dependency) => !dependency.is_internal(),
-                            Err(_) => true,
-                        })
-                        .collect::<Result<
-                            Vec<Dependency<Module, Module>>,
-                            DependencyListFromRepositoryAnalyzeFileError,
-                        >>()
-                },
-            )
-            .partition_map(|result| match result {
+fn analyze_repository<TFrom, TTo, TAnalyzeFile>(
+    files: &RepositoryFiles,
+    analyze_file: TAnalyzeFile,
+) -> DependencyAnalysis<TFrom, TTo>
+where
+    TFrom: Display + Send,
+    TTo: Display + Send,
+    TAnalyzeFile: Fn(
+            &RepositoryFile,
+        )
+            -> Result<Vec<Dependency<TFrom, TTo>>, DependencyListFromRepositoryAnalyzeFileError>
+        + Sync
+        + Send,
+{
+    let repository_path: &Path = files.path().as_ref();
+    let relative_path = |path: &Path| -> PathBuf {
+        path.strip_prefix(repository_path)
+            .unwrap_or(path)
+            .to_path_buf()
+    };
+
+    let (dependencies, file_diagnostics): (Vec<Vec<Dependency<TFrom, TTo>>>, Vec<FileDiagnostic>) =
+        files
+            .iter()
+            .partition_map(|analyzed_file| match analyze_file(analyzed_file) {
                 Ok(deps) => Either::Left(deps),
-                Err(e) => Either::Right(e),
+                Err(e) => Either::Right(FileDiagnostic::new(
+                    Some(relative_path(analyzed_file.file_path().as_ref())),
+                    e,
+                )),
             });
 
-        if !errors.is_empty() {
-            return Err(DependencyListFromRepositoryError::InvalidFiles(errors));
-        }
+    let mut diagnostics: Vec<FileDiagnostic> = files
+        .errors()
+        .iter()
+        .map(|e| FileDiagnostic::new(e.path().map(relative_path), e.clone().into()))
+        .chain(file_diagnostics)
+        .collect();
 
-        Ok(dependencies
+    diagnostics.sort_by(|a, b| a.path().cmp(&b.path()));
+
+    DependencyAnalysis {
+        dependencies: dependencies
             .into_iter()
             .flatten()
-            .collect::<HashSet<Dependency<Module, Module>>>()
-            .into())
+            .collect::<HashSet<Dependency<TFrom, TTo>>>()
+            .into(),
+        diagnostics,
     }
 }
 
-impl TryFrom<Repository> for DependencyList<RepositoryChildPath, Module> {
-    type Error = DependencyListFromRepositoryError;
+impl From<Repository> for DependencyAnalysis<Module, Module> {
+    fn from(repository: Repository) -> Self {
+        DependencyAnalysis::from(&repository.analyzed_files())
+    }
+}
 
-    fn try_from(repository: Repository) -> Result<Self, Self::Error> {
-        let (dependencies, errors): (
-            Vec<Vec<Dependency<RepositoryChildPath, Module>>>,
-            Vec<DependencyListFromRepositoryAnalyzeFileError>,
-        ) = repository
-            .files()
-            .map(
-                |analyzed_file_result| -> Result<
+impl From<&RepositoryFiles> for DependencyAnalysis<Module, Module> {
+    fn from(files: &RepositoryFiles) -> Self {
+        analyze_repository(files, |analyzed_file| {
+            let dependencies: DependencyList<RepositoryChildPath, RepositoryChildPath> =
+                analyzed_file.try_into()?;
+
+            dependencies
+                .as_ref()
+                .iter()
+                .map(
+                    |d| -> Result<
+                        Dependency<Module, Module>,
+                        DependencyListFromRepositoryAnalyzeFileError,
+                    > {
+                        Ok(Dependency::create(d.from.module()?, d.to.module()?))
+                    },
+                )
+                .filter(|dependency_result| match dependency_result {
+                    Ok(dependency) => !dependency.is_internal(),
+                    Err(_) => true,
+                })
+                .collect()
+        })
+    }
+}
+
+impl From<Repository> for DependencyAnalysis<RepositoryChildPath, Module> {
+    fn from(repository: Repository) -> Self {
+        DependencyAnalysis::from(&repository.analyzed_files())
+    }
+}
+
+impl From<&RepositoryFiles> for DependencyAnalysis<RepositoryChildPath, Module> {
+    fn from(files: &RepositoryFiles) -> Self {
+        analyze_repository(files, |analyzed_file| {
+            let dependencies: DependencyList<RepositoryChildPath, RepositoryChildPath> =
+                analyzed_file.try_into()?;
+
+            let mut dependencies = dependencies
+                .as_ref()
+                .iter()
+                .map(
+                    |d| -> Result<
+                        Option<Dependency<RepositoryChildPath, Module>>,
+                        DependencyListFromRepositoryAnalyzeFileError,
+                    > {
+                        let to = d.to.module()?;
+
+                        if d.from.module()? == to {
+                            return Ok(None);
+                        }
+
+                        Ok(Some(Dependency {
+                            from: d.from.clone(),
+                            to,
+                            location: d.location.clone(),
+                        }))
+                    },
+                )
+                .filter_map(|result| result.transpose())
+                .collect::<Result<
                     Vec<Dependency<RepositoryChildPath, Module>>,
                     DependencyListFromRepositoryAnalyzeFileError,
-                > {
-                    let analyzed_file = match analyzed_file_result {
-                        Ok(file) => file,
-                        Err(e) => match e {
-                            RepositoryFilesError::CannotAnalyzeFile(_) => return Ok(vec![]),
-                            _ => return Err(e.into()),
-                        },
-                    };
-
-                    let dependencies: DependencyList<RepositoryChildPath, RepositoryChildPath> =
-                        match analyzed_file.try_into() {
-                            Ok(d) => d,
-                            Err(e) => return Err(e.into()),
-                        };
+                >>()?;
 
-                    dependencies
-                        .as_ref()
-                        .iter()
-                        .map(
-                            |d| -> Result<
-                                Option<Dependency<RepositoryChildPath, Module>>,
-                                DependencyListFromRepositoryAnalyzeFileError,
-                            > {
-                                let (from_result, to_result) = (d.from.module(), d.to.module());
-
-                                let from = match from_result {
-                                    Ok(from_module) => from_module,
-                                    Err(e) => return Err(e.into()),
-                                };
-
-                                let to = match to_result {
-                                    Ok(to_module) => to_module,
-                                    Err(e) => return Err(e.into()),
-                                };
-
-                                if from == to {
-                                    return Ok(None);
-                                }
-
-                                Ok(Some(Dependency::create(d.from.clone(), to)))
-                            },
-                        )
-                        .filter_map(|result| result.transpose())
-                        .collect::<Result<
-                            Vec<Dependency<RepositoryChildPath, Module>>,
-                            DependencyListFromRepositoryAnalyzeFileError,
-                        >>()
-                },
-            )
-            .partition_map(|result| match result {
-                Ok(deps) => Either::Left(deps),
-                Err(e) => Either::Right(e),
+            dependencies.sort_by_key(|dependency| {
+                dependency
+                    .location
+                    .as_ref()
+                    .map(|location| (location.line(), location.column()))
             });
 
-        if !errors.is_empty() {
-            return Err(DependencyListFromRepositoryError::InvalidFiles(errors));
-        }
+            Ok(dependencies)
+        })
+    }
+}
 
-        Ok(dependencies
-            .into_iter()
-            .flatten()
-            .collect::<HashSet<Dependency<RepositoryChildPath, Module>>>()
-            .into())
+impl From<Repository> for DependencyAnalysis<RepositoryChildPath, RepositoryChildPath> {
+    fn from(repository: Repository) -> Self {
+        DependencyAnalysis::from(&repository.analyzed_files())
+    }
+}
+
+impl From<&RepositoryFiles> for DependencyAnalysis<RepositoryChildPath, RepositoryChildPath> {
+    fn from(files: &RepositoryFiles) -> Self {
+        analyze_repository(files, |analyzed_file| {
+            let dependencies: DependencyList<RepositoryChildPath, RepositoryChildPath> =
+                analyzed_file.try_into()?;
+
+            Ok(dependencies.0.into_iter().collect())
+        })
+    }
+}
+
+impl DependencyAnalysis<RepositoryChildPath, RepositoryChildPath> {
+    /// File dependencies that also follow `export ... from` re-exports, so a
+    /// file only exported through a barrel still counts as used.
+    pub fn with_re_exports(files: &RepositoryFiles) -> Self {
+        analyze_repository(files, |analyzed_file| {
+            let dependencies: DependencyList<RepositoryChildPath, RepositoryChildPath> =
+                DependencyList::from_repository_file(
+                    analyzed_file,
+                    &[ImportKind::Import, ImportKind::ReExport],
+                )?;
+
+            Ok(dependencies.0.into_iter().collect())
+        })
+    }
+}
+
+impl TryFrom<Repository> for DependencyList<Module, Module> {
+    type Error = DependencyListFromRepositoryError;
+
+    fn try_from(repository: Repository) -> Result<Self, Self::Error> {
+        DependencyAnalysis::from(repository).into_strict()
+    }
+}
+
+impl TryFrom<Repository> for DependencyList<RepositoryChildPath, Module> {
+    type Error = DependencyListFromRepositoryError;
+
+    fn try_from(repository: Repository) -> Result<Self, Self::Error> {
+        DependencyAnalysis::from(repository).into_strict()
     }
 }
 
@@ -359,48 +791,64 @@ impl TryFrom<Repository> for DependencyList<RepositoryChildPath, RepositoryChild
     type Error = DependencyListFromRepositoryError;
 
     fn try_from(repository: Repository) -> Result<Self, Self::Error> {
-        let (dependencies, errors): (
-            Vec<DependencyList<RepositoryChildPath, RepositoryChildPath>>,
-            Vec<DependencyListFromRepositoryAnalyzeFileError>,
-        ) = repository
-            .files()
-            .map(
-                |analyzed_file_result| -> Result<
-                    DependencyList<RepositoryChildPath, RepositoryChildPath>,
-                    DependencyListFromRepositoryAnalyzeFileError,
-                > {
-                    let analyzed_file = match analyzed_file_result {
-                        Ok(file) => file,
-                        Err(e) => match e {
-                            RepositoryFilesError::CannotAnalyzeFile(_) => {
-                                // This is not ideal, but we are ignoring files that cannot be analyzed
-                                return Ok(HashSet::new().into());
-                            }
-                            _ => return Err(e.into()),
-                        },
-                    };
-
-                    let dependencies: DependencyList<RepositoryChildPath, RepositoryChildPath> =
-                        match analyzed_file.try_into() {
-                            Ok(d) => d,
-                            Err(e) => return Err(e.into()),
-                        };
-                    Ok(dependencies)
-                },
-            )
-            .partition_map(|result| match result {
-                Ok(deps) => Either::Left(deps),
-                Err(e) => Either::Right(e),
-            });
+        DependencyAnalysis::from(repository).into_strict()
+    }
+}
 
-        if !errors.is_empty() {
-            return Err(DependencyListFromRepositoryError::InvalidFiles(errors));
-        }
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-        Ok(dependencies
+    fn list(edges: &[(&str, &str)]) -> DependencyList<String, String> {
+        edges
+            .iter()
+            .map(|(from, to)| Dependency::create(from.to_string(), to.to_string()))
+            .collect::<HashSet<Dependency<String, String>>>()
+            .into()
+    }
+
+    fn paths(edges: &[(&str, &str)], from: &str, to: &str, max_paths: usize) -> Vec<String> {
+        list(edges)
+            .shortest_paths(&from.to_string(), &to.to_string(), max_paths)
             .into_iter()
-            .flat_map(|dep_list| dep_list.0)
-            .collect::<HashSet<Dependency<RepositoryChildPath, RepositoryChildPath>>>()
-            .into())
+            .map(|path| path.join(" > "))
+            .collect()
+    }
+
+    #[test]
+    fn shortest_paths_returns_every_shortest_path_in_order() {
+        let edges = [
+            ("a", "c"),
+            ("a", "b"),
+            ("b", "d"),
+            ("c", "d"),
+            ("a", "e"),
+            ("e", "f"),
+            ("f", "d"),
+        ];
+
+        assert_eq!(paths(&edges, "a", "d", 10), vec!["a > b > d", "a > c > d"]);
+    }
+
+    #[test]
+    fn shortest_paths_keeps_the_first_paths_when_limited() {
+        let edges = [
+            ("a", "x"),
+            ("x", "q"),
+            ("q", "t"),
+            ("a", "y"),
+            ("y", "p"),
+            ("p", "t"),
+        ];
+
+        assert_eq!(paths(&edges, "a", "t", 1), vec!["a > x > q > t"]);
+    }
+
+    #[test]
+    fn shortest_paths_is_empty_without_a_path() {
+        let edges = [("a", "b"), ("c", "a")];
+
+        assert!(paths(&edges, "a", "c", 10).is_empty());
+        assert!(paths(&edges, "a", "a", 10).is_empty());
     }
 }
@@ -1,17 +1,24 @@
 use std::fmt::Display;
 use std::hash::{Hash, Hasher};
 
+use crate::import_path::ImportLocation;
 use crate::module::Module;
 use crate::repository::child::RepositoryChildPath;
 
+pub mod analysis;
 pub mod chain;
+pub mod component;
 pub mod cycle;
+pub mod diff;
+pub mod feedback;
+pub mod level;
 pub mod list;
 
 #[derive(Debug, Clone)]
 pub struct Dependency<TFrom: Display, TTo: Display> {
     pub from: TFrom,
     pub to: TTo,
+    pub location: Option<ImportLocation>,
 }
 
 impl<TFrom: Display, TTo: Display> PartialEq for Dependency<TFrom, TTo> {
@@ -23,7 +30,26 @@ impl<TFrom: Display, TTo: Display> PartialEq for Dependency<TFrom, TTo> {
 
 impl<TFrom: Display, TTo: Display> Dependency<TFrom, TTo> {
     pub fn create(from: TFrom, to: TTo) -> Dependency<TFrom, TTo> {
-        Dependency { from, to }
+        Dependency {
+            from,
+            to,
+            location: None,
+        }
+    }
+
+    pub fn create_at(from: TFrom, to: TTo, location: ImportLocation) -> Dependency<TFrom, TTo> {
+        Dependency {
+            from,
+            to,
+            location: Some(location),
+        }
+    }
+
+    pub fn located_from(&self) -> String {
+        match &self.location {
+            Some(location) => format!("{}:{}", self.from, location),
+            None => self.from.to_string(),
+        }
     }
 
     pub fn to_dot_format(&self) -> String {
@@ -10,6 +10,22 @@ pub enum FilePathContentsError {
     Io(std::io::Error, PathBuf),
 }
 
+impl Display for FilePathContentsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FilePathContentsError::Io(_, path) => write!(f, "could not open {}", path.display()),
+        }
+    }
+}
+
+impl std::error::Error for FilePathContentsError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            FilePathContentsError::Io(e, _) => Some(e),
+        }
+    }
+}
+
 impl FilePath {
     pub fn contents(&self) -> Result<BufReader<File>, FilePathContentsError> {
         let file = File::open(self)
@@ -30,6 +46,22 @@ pub enum FilePathFromEntryError {
     IncorrectPath(FilePathFromPathBufError),
 }
 
+impl Display for FilePathFromEntryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FilePathFromEntryError::IncorrectPath(_) => write!(f, "invalid file path"),
+        }
+    }
+}
+
+impl std::error::Error for FilePathFromEntryError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            FilePathFromEntryError::IncorrectPath(e) => Some(e),
+        }
+    }
+}
+
 impl From<FilePathFromPathBufError> for FilePathFromEntryError {
     fn from(value: FilePathFromPathBufError) -> Self {
         FilePathFromEntryError::IncorrectPath(value)
@@ -52,6 +84,28 @@ pub enum FilePathFromPathBufError {
     Io(std::io::Error, PathBuf),
 }
 
+impl Display for FilePathFromPathBufError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FilePathFromPathBufError::NotAValidFile(path) => {
+                write!(f, "{} is not a TypeScript file", path.display())
+            }
+            FilePathFromPathBufError::Io(_, path) => {
+                write!(f, "could not resolve {}", path.display())
+            }
+        }
+    }
+}
+
+impl std::error::Error for FilePathFromPathBufError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            FilePathFromPathBufError::NotAValidFile(_) => None,
+            FilePathFromPathBufError::Io(e, _) => Some(e),
+        }
+    }
+}
+
 impl TryFrom<PathBuf> for FilePath {
     type Error = FilePathFromPathBufError;
 
@@ -0,0 +1,293 @@
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use git2::{Delta, DiffOptions, build::CheckoutBuilder};
+
+static CHECKOUT_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+#[derive(Debug)]
+pub enum GitError {
+    Git(git2::Error),
+    NoWorkingDirectory(PathBuf),
+    UnknownRevision(String),
+    CouldNotCreateCheckout(std::io::Error),
+}
+
+impl Display for GitError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GitError::Git(_) => write!(f, "could not read the git repository"),
+            GitError::NoWorkingDirectory(path) => {
+                write!(f, "{} is a bare git repository", path.display())
+            }
+            GitError::UnknownRevision(revision) => {
+                write!(f, "{} is not a known revision", revision)
+            }
+            GitError::CouldNotCreateCheckout(_) => {
+                write!(f, "could not create a directory to check out into")
+            }
+        }
+    }
+}
+
+impl std::error::Error for GitError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            GitError::Git(e) => Some(e),
+            GitError::NoWorkingDirectory(_) | GitError::UnknownRevision(_) => None,
+            GitError::CouldNotCreateCheckout(e) => Some(e),
+        }
+    }
+}
+
+impl From<git2::Error> for GitError {
+    fn from(value: git2::Error) -> Self {
+        GitError::Git(value)
+    }
+}
+
+/// The local git repository containing a path. Everything is read from the
+/// local object database, nothing is fetched.
+pub struct GitRepository {
+    repository: git2::Repository,
+    toplevel: PathBuf,
+}
+
+impl GitRepository {
+    pub fn discover(path: &Path) -> Result<Self, GitError> {
+        let repository = git2::Repository::discover(path)?;
+        let toplevel = repository
+            .workdir()
+            .ok_or_else(|| GitError::NoWorkingDirectory(repository.path().to_path_buf()))?
+            .to_path_buf();
+
+        Ok(GitRepository {
+            repository,
+            toplevel: toplevel.canonicalize().unwrap_or(toplevel),
+        })
+    }
+
+    pub fn toplevel(&self) -> &Path {
+        &self.toplevel
+    }
+
+    fn commit(&self, revision: &str) -> Result<git2::Commit<'_>, GitError> {
+        self.repository
+            .revparse_single(revision)
+            .and_then(|object| object.peel_to_commit())
+            .map_err(|_| GitError::UnknownRevision(revision.to_string()))
+    }
+
+    /// Writes the files of `revision` to a temporary directory, leaving the
+    /// working directory, index and `HEAD` untouched.
+    pub fn checkout(&self, revision: &str) -> Result<GitWorktree, GitError> {
+        let commit = self.commit(revision)?;
+
+        let path = std::env::temp_dir().join(format!(
+            "belmarsh-{}-{}-{}",
+            std::process::id(),
+            CHECKOUT_COUNT.fetch_add(1, Ordering::Relaxed),
+            commit.id()
+        ));
+        std::fs::create_dir_all(&path).map_err(GitError::CouldNotCreateCheckout)?;
+
+        let worktree = GitWorktree {
+            path: path.canonicalize().unwrap_or(path),
+        };
+
+        self.repository.checkout_tree(
+            commit.as_object(),
+            Some(
+                CheckoutBuilder::new()
+                    .target_dir(&worktree.path)
+                    .update_index(false)
+                    .force(),
+            ),
+        )?;
+
+        Ok(worktree)
+    }
+
+    /// Files added, modified or deleted since `revision`, including
+    /// uncommitted and untracked ones. Renamed files are listed under both
+    /// their old and new path.
+    pub fn changed_files_since(&self, revision: &str) -> Result<Vec<PathBuf>, GitError> {
+        let tree = self.commit(revision)?.tree()?;
+        let diff = self.repository.diff_tree_to_workdir_with_index(
+            Some(&tree),
+            Some(
+                DiffOptions::new()
+                    .include_untracked(true)
+                    .recurse_untracked_dirs(true),
+            ),
+        )?;
+
+        let mut files: Vec<PathBuf> = diff
+            .deltas()
+            .filter(|delta| delta.status() != Delta::Unmodified)
+            .flat_map(|delta| [delta.old_file().path(), delta.new_file().path()])
+            .flatten()
+            .map(|file| self.toplevel.join(file))
+            .collect();
+
+        files.sort();
+        files.dedup();
+
+        Ok(files)
+    }
+}
+
+/// A checkout of a revision in a temporary directory, removed again when
+/// dropped.
+pub struct GitWorktree {
+    path: PathBuf,
+}
+
+impl GitWorktree {
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl Drop for GitWorktree {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.path);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct TestRepository {
+        path: PathBuf,
+        repository: git2::Repository,
+    }
+
+    impl TestRepository {
+        fn new(name: &str) -> Self {
+            let path = std::env::temp_dir().join(format!(
+                "belmarsh-git-test-{}-{}",
+                std::process::id(),
+                name
+            ));
+            let _ = std::fs::remove_dir_all(&path);
+            std::fs::create_dir_all(&path).unwrap();
+
+            TestRepository {
+                repository: git2::Repository::init(&path).unwrap(),
+                path: path.canonicalize().unwrap(),
+            }
+        }
+
+        fn write(&self, file: &str, contents: &str) {
+            let path = self.path.join(file);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, contents).unwrap();
+        }
+
+        fn commit_all(&self) {
+            let mut index = self.repository.index().unwrap();
+            index
+                .add_all(["*"], git2::IndexAddOption::DEFAULT, None)
+                .unwrap();
+            index.update_all(["*"], None).unwrap();
+            index.write().unwrap();
+
+            let tree = self
+                .repository
+                .find_tree(index.write_tree().unwrap())
+                .unwrap();
+            let signature = git2::Signature::now("test", "test@example.com").unwrap();
+            let parent = self
+                .repository
+                .head()
+                .ok()
+                .and_then(|head| head.peel_to_commit().ok());
+
+            self.repository
+                .commit(
+                    Some("HEAD"),
+                    &signature,
+                    &signature,
+                    "commit",
+                    &tree,
+                    &parent.iter().collect::<Vec<&git2::Commit>>(),
+                )
+                .unwrap();
+        }
+
+        fn changed_files_since(&self, revision: &str) -> Vec<String> {
+            GitRepository::discover(&self.path)
+                .unwrap()
+                .changed_files_since(revision)
+                .unwrap()
+                .into_iter()
+                .map(|file| file.strip_prefix(&self.path).unwrap().display().to_string())
+                .collect()
+        }
+    }
+
+    impl Drop for TestRepository {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.path);
+        }
+    }
+
+    #[test]
+    fn changed_files_since_lists_modified_deleted_and_untracked_files() {
+        let repository = TestRepository::new("changed");
+        repository.write("a/index.ts", "export const a = 1;");
+        repository.write("b/index.ts", "export const b = 1;");
+        repository.write("c/index.ts", "export const c = 1;");
+        repository.commit_all();
+
+        repository.write("a/index.ts", "export const a = 2;");
+        std::fs::remove_file(repository.path.join("c/index.ts")).unwrap();
+        repository.write("d/index.ts", "export const d = 1;");
+
+        assert_eq!(
+            repository.changed_files_since("HEAD"),
+            vec!["a/index.ts", "c/index.ts", "d/index.ts"]
+        );
+    }
+
+    #[test]
+    fn changed_files_since_rejects_unknown_revisions() {
+        let repository = TestRepository::new("unknown");
+        repository.write("a/index.ts", "export const a = 1;");
+        repository.commit_all();
+
+        assert!(matches!(
+            GitRepository::discover(&repository.path)
+                .unwrap()
+                .changed_files_since("does-not-exist"),
+            Err(GitError::UnknownRevision(_))
+        ));
+    }
+
+    #[test]
+    fn checkout_writes_the_revision_without_touching_the_working_directory() {
+        let repository = TestRepository::new("checkout");
+        repository.write("a/index.ts", "export const a = 1;");
+        repository.commit_all();
+        repository.write("a/index.ts", "export const a = 2;");
+
+        let git_repository = GitRepository::discover(&repository.path).unwrap();
+        let worktree = git_repository.checkout("HEAD").unwrap();
+        let checkout_path = worktree.path().to_path_buf();
+
+        assert_eq!(
+            std::fs::read_to_string(checkout_path.join("a/index.ts")).unwrap(),
+            "export const a = 1;"
+        );
+        assert_eq!(
+            std::fs::read_to_string(repository.path.join("a/index.ts")).unwrap(),
+            "export const a = 2;"
+        );
+
+        drop(worktree);
+        assert!(!checkout_path.exists());
+    }
+}
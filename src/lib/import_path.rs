@@ -1,4 +1,5 @@
 use crate::file_parent_path::FileParentPath;
+use std::fmt::Display;
 use std::path::{Path, PathBuf};
 
 #[derive(Debug)]
@@ -6,18 +7,181 @@ pub enum ImportPathFromImportStringError {
     CannotFindFile(String),
 }
 
+impl Display for ImportPathFromImportStringError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ImportPathFromImportStringError::CannotFindFile(path) => {
+                write!(f, "could not find {}", path)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ImportPathFromImportStringError {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImportKind {
+    Import,
+    ReExport,
+}
+
+impl ImportKind {
+    pub fn from_keyword(keyword: &str) -> Self {
+        if keyword == "export" {
+            ImportKind::ReExport
+        } else {
+            ImportKind::Import
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImportSymbol {
+    imported: String,
+    local: String,
+}
+
+impl ImportSymbol {
+    pub fn imported(&self) -> &str {
+        &self.imported
+    }
+
+    pub fn local(&self) -> &str {
+        &self.local
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ImportSymbols {
+    Named(Vec<ImportSymbol>),
+    /// `* as name`, every symbol under a single local name.
+    Namespace(String),
+    All,
+}
+
+impl ImportSymbols {
+    pub fn from_clause(clause: &str) -> Self {
+        let clause = clause.trim();
+
+        if let Some(namespace) = clause.strip_prefix('*') {
+            return match namespace.trim().strip_prefix("as ") {
+                Some(name) => ImportSymbols::Namespace(name.trim().to_string()),
+                None => ImportSymbols::All,
+            };
+        }
+
+        ImportSymbols::Named(
+            clause
+                .trim_start_matches('{')
+                .trim_end_matches('}')
+                .split(',')
+                .map(|specifier| specifier.trim())
+                .map(|specifier| specifier.strip_prefix("type ").unwrap_or(specifier).trim())
+                .filter(|specifier| !specifier.is_empty())
+                .map(|specifier| match specifier.split_once(" as ") {
+                    Some((imported, local)) => ImportSymbol {
+                        imported: imported.trim().to_string(),
+                        local: local.trim().to_string(),
+                    },
+                    None => ImportSymbol {
+                        imported: specifier.to_string(),
+                        local: specifier.to_string(),
+                    },
+                })
+                .collect(),
+        )
+    }
+}
+
+/// Where an import specifier appears in its file. Both `line` and `column`
+/// are 1-based, and `column` points at the first character of the specifier
+/// inside its quotes rather than at the start of the statement.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImportLocation {
+    line: usize,
+    column: usize,
+    specifier: String,
+}
+
+impl ImportLocation {
+    pub fn new(line: usize, column: usize, specifier: String) -> Self {
+        ImportLocation {
+            line,
+            column,
+            specifier,
+        }
+    }
+
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    pub fn specifier(&self) -> &str {
+        &self.specifier
+    }
+}
+
+impl Display for ImportLocation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}", self.line, self.column)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct UnresolvedImport {
+    location: ImportLocation,
+    statement_line: usize,
+}
+
+impl UnresolvedImport {
+    pub fn new(location: ImportLocation, statement_line: usize) -> Self {
+        UnresolvedImport {
+            location,
+            statement_line,
+        }
+    }
+
+    pub fn specifier(&self) -> &str {
+        self.location.specifier()
+    }
+
+    pub fn line(&self) -> usize {
+        self.location.line()
+    }
+
+    /// Line of the `import` or `export` keyword, which suppression comments
+    /// refer to when a statement spans several lines.
+    pub fn statement_line(&self) -> usize {
+        self.statement_line
+    }
+
+    pub fn location(&self) -> &ImportLocation {
+        &self.location
+    }
+}
+
 #[derive(Debug)]
-pub struct ImportPath(PathBuf);
+pub struct ImportPath {
+    path: PathBuf,
+    location: ImportLocation,
+    statement_line: usize,
+    kind: ImportKind,
+    symbols: ImportSymbols,
+}
 
 impl Into<PathBuf> for ImportPath {
     fn into(self) -> PathBuf {
-        self.0
+        self.path
     }
 }
 
 impl AsRef<Path> for ImportPath {
     fn as_ref(&self) -> &Path {
-        &self.0.as_ref()
+        self.path.as_ref()
     }
 }
 
@@ -25,6 +189,10 @@ impl ImportPath {
     pub fn from_import_string(
         import_path: &str,
         cwd: &FileParentPath,
+        location: ImportLocation,
+        statement_line: usize,
+        kind: ImportKind,
+        symbols: ImportSymbols,
     ) -> Result<Self, ImportPathFromImportStringError> {
         let base_path: &Path = cwd.as_ref();
         let resolved_path = if import_path.ends_with(".ts") {
@@ -52,11 +220,98 @@ impl ImportPath {
         };
 
         if let Ok(canonicalized_path) = resolved_path.canonicalize() {
-            Ok(ImportPath(canonicalized_path))
+            Ok(ImportPath {
+                path: canonicalized_path,
+                location,
+                statement_line,
+                kind,
+                symbols,
+            })
         } else {
             Err(ImportPathFromImportStringError::CannotFindFile(
                 resolved_path.display().to_string(),
             ))
         }
     }
+
+    pub fn line(&self) -> usize {
+        self.location.line()
+    }
+
+    /// Line of the `import` or `export` keyword, which suppression comments
+    /// refer to when a statement spans several lines.
+    pub fn statement_line(&self) -> usize {
+        self.statement_line
+    }
+
+    pub fn location(&self) -> &ImportLocation {
+        &self.location
+    }
+
+    pub fn kind(&self) -> ImportKind {
+        self.kind
+    }
+
+    pub fn symbols(&self) -> &ImportSymbols {
+        &self.symbols
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn named(symbols: &[(&str, &str)]) -> ImportSymbols {
+        ImportSymbols::Named(
+            symbols
+                .iter()
+                .map(|(imported, local)| ImportSymbol {
+                    imported: imported.to_string(),
+                    local: local.to_string(),
+                })
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn from_clause_reads_named_symbols() {
+        assert_eq!(
+            ImportSymbols::from_clause("{ A, B }"),
+            named(&[("A", "A"), ("B", "B")])
+        );
+    }
+
+    #[test]
+    fn from_clause_reads_aliases_and_type_imports() {
+        assert_eq!(
+            ImportSymbols::from_clause("{ A as Alias, type T }"),
+            named(&[("A", "Alias"), ("T", "T")])
+        );
+    }
+
+    #[test]
+    fn from_clause_reads_multi_line_clauses() {
+        assert_eq!(
+            ImportSymbols::from_clause("{\n  A,\n  B as C,\n}"),
+            named(&[("A", "A"), ("B", "C")])
+        );
+    }
+
+    #[test]
+    fn from_clause_reads_namespaces() {
+        assert_eq!(
+            ImportSymbols::from_clause("* as utils"),
+            ImportSymbols::Namespace("utils".to_string())
+        );
+    }
+
+    #[test]
+    fn from_clause_reads_star_re_exports() {
+        assert_eq!(ImportSymbols::from_clause("*"), ImportSymbols::All);
+    }
+
+    #[test]
+    fn from_clause_ignores_empty_clauses() {
+        assert_eq!(ImportSymbols::from_clause("{ }"), named(&[]));
+    }
 }
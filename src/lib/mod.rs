@@ -1,7 +1,9 @@
 pub mod dependency;
 pub mod file_parent_path;
 pub mod file_path;
+pub mod git;
 pub mod import_path;
 pub mod module;
 pub mod module_mapping;
 pub mod repository;
+pub mod suppression;
@@ -6,6 +6,18 @@ pub enum ModuleFromComponentError {
     InvalidComponent(String),
 }
 
+impl Display for ModuleFromComponentError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ModuleFromComponentError::InvalidComponent(component) => {
+                write!(f, "'{}' is not a valid module name", component)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ModuleFromComponentError {}
+
 fn component_to_string(component: Component) -> String {
     match component {
         Component::Normal(os_str) => os_str.to_string_lossy().into_owned(),
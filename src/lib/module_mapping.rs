@@ -1,4 +1,5 @@
 use std::collections::HashSet;
+use std::fmt::Display;
 
 #[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub struct ModuleMapping {
@@ -11,6 +12,18 @@ pub enum ModuleMappingFromParamStringError {
     InvalidFormat(String),
 }
 
+impl Display for ModuleMappingFromParamStringError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ModuleMappingFromParamStringError::InvalidFormat(param) => {
+                write!(f, "invalid module mapping '{}', expected ALIAS:PATH", param)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ModuleMappingFromParamStringError {}
+
 impl ModuleMapping {
     pub fn from_param_string(
         param_string: &str,
@@ -29,7 +42,11 @@ impl ModuleMapping {
     }
 
     pub fn replace_import_alias(&self, line: &str) -> String {
-        if line.trim_start().starts_with("import") && line.contains(&self.from) {
+        let trimmed_line = line.trim_start();
+
+        if (trimmed_line.starts_with("import") || trimmed_line.starts_with("export"))
+            && line.contains(&self.from)
+        {
             line.replace(&self.from, &self.to)
         } else {
             line.to_string()
@@ -45,6 +62,24 @@ pub enum ModuleMappingsFromParamStringsError {
     InvalidParam(ModuleMappingFromParamStringError),
 }
 
+impl Display for ModuleMappingsFromParamStringsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ModuleMappingsFromParamStringsError::InvalidParam(_) => {
+                write!(f, "could not parse module mappings")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ModuleMappingsFromParamStringsError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ModuleMappingsFromParamStringsError::InvalidParam(e) => Some(e),
+        }
+    }
+}
+
 impl From<ModuleMappingFromParamStringError> for ModuleMappingsFromParamStringsError {
     fn from(value: ModuleMappingFromParamStringError) -> Self {
         ModuleMappingsFromParamStringsError::InvalidParam(value)
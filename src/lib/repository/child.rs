@@ -18,12 +18,35 @@ pub enum RepositoryChildPathModuleError {
     ModuleConversionError(ModuleFromComponentError),
 }
 
+impl Display for RepositoryChildPathModuleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RepositoryChildPathModuleError::CouldNotGetModule(path) => {
+                write!(f, "could not determine the module of {}", path)
+            }
+            RepositoryChildPathModuleError::ModuleConversionError(_) => {
+                write!(f, "could not determine module")
+            }
+        }
+    }
+}
+
+impl std::error::Error for RepositoryChildPathModuleError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            RepositoryChildPathModuleError::CouldNotGetModule(_) => None,
+            RepositoryChildPathModuleError::ModuleConversionError(e) => Some(e),
+        }
+    }
+}
+
 impl From<RepositoryChildPathFromFilePathError> for RepositoryChildPathModuleError {
     fn from(value: RepositoryChildPathFromFilePathError) -> Self {
-        RepositoryChildPathModuleError::CouldNotGetModule(format!(
-            "Could not get module from file path error: {:?}",
-            value
-        ))
+        match value {
+            RepositoryChildPathFromFilePathError::Path(
+                RepositoryChildPathFromPathError::ImportOutsideRoot(path),
+            ) => RepositoryChildPathModuleError::CouldNotGetModule(path),
+        }
     }
 }
 
@@ -38,6 +61,24 @@ pub enum RepositoryChildPathFromImportPathError {
     Path(RepositoryChildPathFromPathError),
 }
 
+impl Display for RepositoryChildPathFromImportPathError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RepositoryChildPathFromImportPathError::Path(_) => {
+                write!(f, "could not resolve import within repository")
+            }
+        }
+    }
+}
+
+impl std::error::Error for RepositoryChildPathFromImportPathError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            RepositoryChildPathFromImportPathError::Path(e) => Some(e),
+        }
+    }
+}
+
 impl From<RepositoryChildPathFromPathError> for RepositoryChildPathFromImportPathError {
     fn from(value: RepositoryChildPathFromPathError) -> Self {
         RepositoryChildPathFromImportPathError::Path(value)
@@ -49,6 +90,24 @@ pub enum RepositoryChildPathFromFilePathError {
     Path(RepositoryChildPathFromPathError),
 }
 
+impl Display for RepositoryChildPathFromFilePathError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RepositoryChildPathFromFilePathError::Path(_) => {
+                write!(f, "could not locate file within repository")
+            }
+        }
+    }
+}
+
+impl std::error::Error for RepositoryChildPathFromFilePathError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            RepositoryChildPathFromFilePathError::Path(e) => Some(e),
+        }
+    }
+}
+
 impl From<RepositoryChildPathFromPathError> for RepositoryChildPathFromFilePathError {
     fn from(value: RepositoryChildPathFromPathError) -> Self {
         RepositoryChildPathFromFilePathError::Path(value)
@@ -60,12 +119,46 @@ pub enum RepositoryChildPathFromPathError {
     ImportOutsideRoot(String),
 }
 
+impl Display for RepositoryChildPathFromPathError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RepositoryChildPathFromPathError::ImportOutsideRoot(path) => {
+                write!(f, "{} is outside the repository root", path)
+            }
+        }
+    }
+}
+
+impl std::error::Error for RepositoryChildPathFromPathError {}
+
 #[derive(Debug)]
 pub enum RepositoryChildPathFromRepositoryFileError {
     File(RepositoryChildPathFromImportPathError),
     FilePath(RepositoryChildPathFromFilePathError),
 }
 
+impl Display for RepositoryChildPathFromRepositoryFileError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RepositoryChildPathFromRepositoryFileError::File(_) => {
+                write!(f, "could not locate file within repository")
+            }
+            RepositoryChildPathFromRepositoryFileError::FilePath(_) => {
+                write!(f, "could not locate file within repository")
+            }
+        }
+    }
+}
+
+impl std::error::Error for RepositoryChildPathFromRepositoryFileError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            RepositoryChildPathFromRepositoryFileError::File(e) => Some(e),
+            RepositoryChildPathFromRepositoryFileError::FilePath(e) => Some(e),
+        }
+    }
+}
+
 impl From<RepositoryChildPathFromFilePathError> for RepositoryChildPathFromRepositoryFileError {
     fn from(value: RepositoryChildPathFromFilePathError) -> Self {
         RepositoryChildPathFromRepositoryFileError::FilePath(value)
@@ -118,7 +211,7 @@ impl RepositoryChildPath {
         )?)
     }
 
-    fn from_path(
+    pub(crate) fn from_path(
         path: &Path,
         repository_path: &RepositoryPath,
     ) -> Result<RepositoryChildPath, RepositoryChildPathFromPathError> {
@@ -144,6 +237,16 @@ impl RepositoryChildPath {
         self.barrel
     }
 
+    /// The `index.ts` at the root of a module, other barrels such as
+    /// `testing.ts` are secondary entry points.
+    pub fn is_module_barrel(&self) -> bool {
+        self.path.components().count() == 2
+            && self
+                .path
+                .file_name()
+                .is_some_and(|file_name| file_name == "index.ts")
+    }
+
     pub fn module(&self) -> Result<Module, RepositoryChildPathModuleError> {
         let component = self.path.components().next().ok_or_else(|| {
             RepositoryChildPathModuleError::CouldNotGetModule(self.path.display().to_string())
@@ -153,6 +256,12 @@ impl RepositoryChildPath {
     }
 }
 
+impl AsRef<Path> for RepositoryChildPath {
+    fn as_ref(&self) -> &Path {
+        &self.path
+    }
+}
+
 impl Display for RepositoryChildPath {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.path.display())
@@ -1,6 +1,6 @@
 use crate::file_parent_path::FileParentPath;
 use crate::file_path::{FilePath, FilePathContentsError, FilePathFromEntryError};
-use crate::import_path::{ImportPath, ImportPathFromImportStringError};
+use crate::import_path::{ImportKind, ImportLocation, ImportPath, ImportSymbols, UnresolvedImport};
 use crate::module::Module;
 use crate::module_mapping::ModuleMappings;
 use crate::repository::{
@@ -9,9 +9,11 @@ use crate::repository::{
     },
     path::RepositoryPath,
 };
+use crate::suppression::Suppressions;
 use lazy_static::lazy_static;
 use once_cell::sync::OnceCell;
 use regex::Regex;
+use std::fmt::Display;
 use std::{io::BufRead, path::PathBuf};
 
 #[derive(Debug)]
@@ -19,6 +21,24 @@ pub enum RepositoryFileFromEntryError {
     FilePathError(FilePathFromEntryError),
 }
 
+impl Display for RepositoryFileFromEntryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RepositoryFileFromEntryError::FilePathError(_) => {
+                write!(f, "could not read directory entry")
+            }
+        }
+    }
+}
+
+impl std::error::Error for RepositoryFileFromEntryError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            RepositoryFileFromEntryError::FilePathError(e) => Some(e),
+        }
+    }
+}
+
 impl From<FilePathFromEntryError> for RepositoryFileFromEntryError {
     fn from(value: FilePathFromEntryError) -> Self {
         RepositoryFileFromEntryError::FilePathError(value)
@@ -31,6 +51,28 @@ pub enum RepositoryFileModuleError {
     FilePath(RepositoryChildPathFromFilePathError),
 }
 
+impl Display for RepositoryFileModuleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RepositoryFileModuleError::Module(_) => {
+                write!(f, "could not determine the module of the file")
+            }
+            RepositoryFileModuleError::FilePath(_) => {
+                write!(f, "could not locate file within repository")
+            }
+        }
+    }
+}
+
+impl std::error::Error for RepositoryFileModuleError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            RepositoryFileModuleError::Module(e) => Some(e),
+            RepositoryFileModuleError::FilePath(e) => Some(e),
+        }
+    }
+}
+
 impl From<RepositoryChildPathModuleError> for RepositoryFileModuleError {
     fn from(value: RepositoryChildPathModuleError) -> Self {
         RepositoryFileModuleError::Module(value)
@@ -46,16 +88,62 @@ impl From<RepositoryChildPathFromFilePathError> for RepositoryFileModuleError {
 #[derive(Debug)]
 pub enum RepositoryFileResolveImportsError {
     Io(std::io::Error, PathBuf),
-    ParseImportPath(ImportPathFromImportStringError, FilePath),
     CannotGetContents(FilePathContentsError),
 }
 
+impl Display for RepositoryFileResolveImportsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RepositoryFileResolveImportsError::Io(_, path) => {
+                write!(f, "could not read {}", path.display())
+            }
+            RepositoryFileResolveImportsError::CannotGetContents(_) => {
+                write!(f, "could not read imports")
+            }
+        }
+    }
+}
+
+impl std::error::Error for RepositoryFileResolveImportsError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            RepositoryFileResolveImportsError::Io(e, _) => Some(e),
+            RepositoryFileResolveImportsError::CannotGetContents(e) => Some(e),
+        }
+    }
+}
+
 impl From<FilePathContentsError> for RepositoryFileResolveImportsError {
     fn from(value: FilePathContentsError) -> Self {
         RepositoryFileResolveImportsError::CannotGetContents(value)
     }
 }
 
+/// A name a file declares and exports itself, such as `export const name`.
+#[derive(Debug, Clone)]
+pub struct DeclaredExport {
+    name: String,
+    line: usize,
+}
+
+impl DeclaredExport {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn line(&self) -> usize {
+        self.line
+    }
+}
+
+#[derive(Debug)]
+struct RepositoryFileImports {
+    resolved: Vec<ImportPath>,
+    unresolved: Vec<UnresolvedImport>,
+    suppressions: Suppressions,
+    declared_exports: Vec<DeclaredExport>,
+}
+
 #[derive(Debug)]
 pub struct RepositoryFile {
     file_path: FilePath,
@@ -63,7 +151,7 @@ pub struct RepositoryFile {
     import_mappings: ModuleMappings,
 
     module: OnceCell<Module>,
-    imports: OnceCell<Vec<ImportPath>>,
+    imports: OnceCell<RepositoryFileImports>,
 }
 
 impl AsRef<RepositoryPath> for RepositoryFile {
@@ -104,52 +192,248 @@ impl RepositoryFile {
     }
 
     pub fn imports(&self) -> Result<&[ImportPath], RepositoryFileResolveImportsError> {
+        self.resolve_imports()
+            .map(|imports| imports.resolved.as_slice())
+    }
+
+    pub fn unresolved_imports(
+        &self,
+    ) -> Result<&[UnresolvedImport], RepositoryFileResolveImportsError> {
+        self.resolve_imports()
+            .map(|imports| imports.unresolved.as_slice())
+    }
+
+    pub fn suppressions(&self) -> Result<&Suppressions, RepositoryFileResolveImportsError> {
+        self.resolve_imports().map(|imports| &imports.suppressions)
+    }
+
+    pub fn declared_exports(&self) -> Result<&[DeclaredExport], RepositoryFileResolveImportsError> {
+        self.resolve_imports()
+            .map(|imports| imports.declared_exports.as_slice())
+    }
+
+    /// The index of the line closing an import or export clause that starts on
+    /// `start` and spans several lines.
+    fn statement_end(lines: &[String], start: usize) -> usize {
         lazy_static! {
-            static ref IMPORT_REGEX: Regex =
-                Regex::new(r"import\s*\{[^}]*\}\s*from\s*'([\.\/][^']+)';")
-                    .expect("Failed to compile regex");
+            static ref MULTI_LINE_CLAUSE_REGEX: Regex =
+                Regex::new(r"^\s*(import|export)\s*\{[^}]*$").expect("Failed to compile regex");
         }
 
-        self.imports
-            .get_or_try_init(|| {
-                let mut imports = Vec::new();
-                let reader = self.file_path.contents()?;
-                let parent_dir: FileParentPath = FileParentPath::from_file_path(&self.file_path);
-
-                for line in reader.lines() {
-                    let line = self
-                        .import_mappings
-                        .replace_import_aliases(&line.map_err(|e| {
-                            RepositoryFileResolveImportsError::Io(
-                                e,
-                                self.file_path.as_ref().to_path_buf(),
-                            )
-                        })?);
-
-                    if let Some(captures) = IMPORT_REGEX.captures(&line) {
-                        if let Some(path_capture) = captures.get(1) {
-                            let import_path = match ImportPath::from_import_string(
-                                path_capture.as_str(),
-                                &parent_dir,
-                            )
-                            .map_err(|e| {
-                                RepositoryFileResolveImportsError::ParseImportPath(
-                                    e,
-                                    self.file_path.clone(),
+        if !MULTI_LINE_CLAUSE_REGEX.is_match(&lines[start]) {
+            return start;
+        }
+
+        lines
+            .iter()
+            .enumerate()
+            .skip(start + 1)
+            .find(|(_, line)| line.contains('}'))
+            .map_or(start, |(line_index, _)| line_index)
+    }
+
+    /// Line and column of byte offset `offset` in a statement starting on
+    /// `first_line`.
+    fn position_in_statement(statement: &str, first_line: usize, offset: usize) -> (usize, usize) {
+        let before = &statement[..offset];
+        let line = first_line + before.matches('\n').count();
+        let column = before
+            .rsplit('\n')
+            .next()
+            .unwrap_or_default()
+            .chars()
+            .count()
+            + 1;
+
+        (line, column)
+    }
+
+    fn resolve_imports(&self) -> Result<&RepositoryFileImports, RepositoryFileResolveImportsError> {
+        lazy_static! {
+            static ref IMPORT_REGEX: Regex = Regex::new(
+                r"(import|export)\s*(\{[^}]*\}|\*\s*as\s+[\w$]+|\*)\s*from\s*'([\.\/][^']+)';"
+            )
+            .expect("Failed to compile regex");
+            static ref SPECIFIER_REGEX: Regex = Regex::new(
+                r"(import|export)\s*(\{[^}]*\}|\*\s*as\s+[\w$]+|\*)\s*from\s*'([^']+)';"
+            )
+            .expect("Failed to compile regex");
+            static ref DECLARED_EXPORT_REGEX: Regex = Regex::new(
+                r"^\s*export\s+(?:declare\s+)?(?:default\s+)?(?:abstract\s+)?(?:async\s+)?(?:const|let|var|function\*?|class|interface|type|enum|namespace)\s+([\w$]+)"
+            )
+            .expect("Failed to compile regex");
+        }
+
+        self.imports.get_or_try_init(|| {
+            let mut resolved = Vec::new();
+            let mut unresolved = Vec::new();
+            let mut suppressions = Suppressions::default();
+            let mut declared_exports = Vec::new();
+            let reader = self.file_path.contents()?;
+            let parent_dir: FileParentPath = FileParentPath::from_file_path(&self.file_path);
+
+            let lines: Vec<String> =
+                reader
+                    .lines()
+                    .collect::<Result<Vec<String>, _>>()
+                    .map_err(|e| {
+                        RepositoryFileResolveImportsError::Io(
+                            e,
+                            self.file_path.as_ref().to_path_buf(),
+                        )
+                    })?;
+
+            for (line_index, line) in lines.iter().enumerate() {
+                suppressions.record_line(line_index + 1, line);
+
+                if let Some(name) = DECLARED_EXPORT_REGEX
+                    .captures(line)
+                    .and_then(|captures| captures.get(1))
+                {
+                    declared_exports.push(DeclaredExport {
+                        name: name.as_str().to_string(),
+                        line: line_index + 1,
+                    });
+                }
+            }
+
+            let mut line_index = 0;
+
+            while line_index < lines.len() {
+                let statement_end = Self::statement_end(&lines, line_index);
+                let raw_statement = lines[line_index..=statement_end].join("\n");
+                let statement = self.import_mappings.replace_import_aliases(&raw_statement);
+
+                if let Some(captures) = IMPORT_REGEX.captures(&statement) {
+                    if let (Some(keyword_capture), Some(clause_capture), Some(path_capture)) =
+                        (captures.get(1), captures.get(2), captures.get(3))
+                    {
+                        let (statement_line, _) = Self::position_in_statement(
+                            &statement,
+                            line_index + 1,
+                            keyword_capture.start(),
+                        );
+                        let raw_specifier = SPECIFIER_REGEX
+                            .captures(&raw_statement)
+                            .and_then(|raw_captures| raw_captures.get(3));
+                        let location = match raw_specifier {
+                            Some(raw_specifier) => {
+                                let (line, column) = Self::position_in_statement(
+                                    &raw_statement,
+                                    line_index + 1,
+                                    raw_specifier.start(),
+                                );
+
+                                ImportLocation::new(
+                                    line,
+                                    column,
+                                    raw_specifier.as_str().to_string(),
                                 )
-                            }) {
-                                Ok(path) => path,
-                                Err(e) => {
-                                    eprintln!("{:?}", e);
-                                    continue;
-                                }
-                            };
-                            imports.push(import_path);
+                            }
+                            None => {
+                                let (line, column) = Self::position_in_statement(
+                                    &statement,
+                                    line_index + 1,
+                                    path_capture.start(),
+                                );
+
+                                ImportLocation::new(line, column, path_capture.as_str().to_string())
+                            }
+                        };
+
+                        match ImportPath::from_import_string(
+                            path_capture.as_str(),
+                            &parent_dir,
+                            location.clone(),
+                            statement_line,
+                            ImportKind::from_keyword(keyword_capture.as_str()),
+                            ImportSymbols::from_clause(clause_capture.as_str()),
+                        ) {
+                            Ok(import_path) => resolved.push(import_path),
+                            Err(_) => {
+                                unresolved.push(UnresolvedImport::new(location, statement_line))
+                            }
                         }
                     }
                 }
-                Ok(imports)
+
+                line_index = statement_end + 1;
+            }
+
+            Ok(RepositoryFileImports {
+                resolved,
+                unresolved,
+                suppressions,
+                declared_exports,
             })
-            .map(|v| v.as_slice())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rayon::prelude::*;
+
+    use super::*;
+
+    fn lines(source: &str) -> Vec<String> {
+        source.lines().map(String::from).collect()
+    }
+
+    #[test]
+    fn statement_end_joins_multi_line_clauses() {
+        let lines = lines("import {\n  A,\n  B,\n} from '../a';\nexport const C = 1;");
+
+        assert_eq!(RepositoryFile::statement_end(&lines, 0), 3);
+        assert_eq!(RepositoryFile::statement_end(&lines, 4), 4);
+    }
+
+    #[test]
+    fn statement_end_leaves_other_blocks_alone() {
+        let lines = lines("export function f() {\n  return 1;\n}");
+
+        assert_eq!(RepositoryFile::statement_end(&lines, 0), 0);
+    }
+
+    #[test]
+    fn position_in_statement_counts_lines_and_columns() {
+        let statement = "import {\n  A,\n} from '../a';";
+        let offset = statement.find("../a").unwrap();
+
+        assert_eq!(
+            RepositoryFile::position_in_statement(statement, 10, offset),
+            (12, 9)
+        );
+    }
+
+    #[test]
+    fn imports_record_the_line_of_the_statement() {
+        let path = std::env::temp_dir().join(format!("belmarsh-statement-{}", std::process::id()));
+        std::fs::create_dir_all(path.join("a")).unwrap();
+        std::fs::write(path.join("a/x.ts"), "export const A = 1;\n").unwrap();
+        std::fs::write(
+            path.join("a/index.ts"),
+            "// belmarsh-ignore-next-line\nimport {\n  A,\n} from './x';\n",
+        )
+        .unwrap();
+
+        let repository =
+            crate::repository::Repository::try_from(path.display().to_string()).unwrap();
+        let files = repository.analyzed_files();
+        let file: &RepositoryFile = files
+            .readable()
+            .find_any(|file| file.file_path().as_ref().ends_with("a/index.ts"))
+            .unwrap();
+        let import_path = &file.imports().unwrap()[0];
+
+        assert_eq!(import_path.statement_line(), 2);
+        assert_eq!(import_path.line(), 4);
+        assert!(
+            file.suppressions()
+                .unwrap()
+                .is_line_suppressed("circular-files", 2)
+        );
+
+        std::fs::remove_dir_all(&path).unwrap();
     }
 }
@@ -1,12 +1,17 @@
+use std::fmt::Display;
 pub mod child;
 pub mod file;
 pub mod path;
 
+use crate::file_path::{FilePathFromEntryError, FilePathFromPathBufError};
 use crate::{module::Module, module_mapping::ModuleMappings};
+use child::{RepositoryChildPath, RepositoryChildPathFromPathError};
 use file::{RepositoryFile, RepositoryFileFromEntryError};
 use path::{RepositoryPath, RepositoryPathFromStringError};
-use rayon::prelude::*;
+use rayon::{iter::Either, prelude::*};
 use std::collections::HashSet;
+use std::path::{Component, Path, PathBuf};
+use std::sync::Arc;
 use walkdir::WalkDir;
 
 #[derive(Debug)]
@@ -15,6 +20,51 @@ pub enum RepositoryFilesError {
     CannotAnalyzeFile(RepositoryFileFromEntryError),
 }
 
+impl Display for RepositoryFilesError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RepositoryFilesError::CannotScanFiles(_) => {
+                write!(f, "could not scan repository files")
+            }
+            RepositoryFilesError::CannotAnalyzeFile(_) => write!(f, "could not analyze file"),
+        }
+    }
+}
+
+impl std::error::Error for RepositoryFilesError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            RepositoryFilesError::CannotScanFiles(e) => Some(e),
+            RepositoryFilesError::CannotAnalyzeFile(e) => Some(e),
+        }
+    }
+}
+
+impl RepositoryFilesError {
+    pub fn is_not_source_file(&self) -> bool {
+        matches!(
+            self,
+            RepositoryFilesError::CannotAnalyzeFile(RepositoryFileFromEntryError::FilePathError(
+                FilePathFromEntryError::IncorrectPath(FilePathFromPathBufError::NotAValidFile(_))
+            ))
+        )
+    }
+
+    pub fn path(&self) -> Option<&Path> {
+        match self {
+            RepositoryFilesError::CannotScanFiles(e) => e.path(),
+            RepositoryFilesError::CannotAnalyzeFile(
+                RepositoryFileFromEntryError::FilePathError(FilePathFromEntryError::IncorrectPath(
+                    e,
+                )),
+            ) => match e {
+                FilePathFromPathBufError::NotAValidFile(path)
+                | FilePathFromPathBufError::Io(_, path) => Some(path),
+            },
+        }
+    }
+}
+
 impl From<RepositoryFileFromEntryError> for RepositoryFilesError {
     fn from(value: RepositoryFileFromEntryError) -> Self {
         RepositoryFilesError::CannotAnalyzeFile(value)
@@ -27,6 +77,36 @@ impl From<walkdir::Error> for RepositoryFilesError {
     }
 }
 
+/// The source files of a repository, each read and parsed once, along with
+/// the entries that could not be scanned.
+#[derive(Debug)]
+pub struct RepositoryFiles {
+    path: RepositoryPath,
+    files: Vec<RepositoryFile>,
+    errors: Vec<Arc<RepositoryFilesError>>,
+}
+
+impl RepositoryFiles {
+    pub fn path(&self) -> &RepositoryPath {
+        &self.path
+    }
+
+    pub fn iter(&self) -> impl ParallelIterator<Item = &RepositoryFile> {
+        self.files.par_iter()
+    }
+
+    pub fn errors(&self) -> &[Arc<RepositoryFilesError>] {
+        &self.errors
+    }
+
+    /// Files whose contents could be read. The dependency analysis reports
+    /// the others once as diagnostics.
+    pub fn readable(&self) -> impl ParallelIterator<Item = &RepositoryFile> {
+        self.iter()
+            .filter(|analyzed_file| analyzed_file.imports().is_ok())
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Repository {
     path: RepositoryPath,
@@ -39,6 +119,24 @@ pub enum RepositoryFromStringError {
     CouldNotCreateRepositoryPath(RepositoryPathFromStringError),
 }
 
+impl Display for RepositoryFromStringError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RepositoryFromStringError::CouldNotCreateRepositoryPath(_) => {
+                write!(f, "invalid repository path")
+            }
+        }
+    }
+}
+
+impl std::error::Error for RepositoryFromStringError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            RepositoryFromStringError::CouldNotCreateRepositoryPath(e) => Some(e),
+        }
+    }
+}
+
 impl From<RepositoryPathFromStringError> for RepositoryFromStringError {
     fn from(value: RepositoryPathFromStringError) -> Self {
         RepositoryFromStringError::CouldNotCreateRepositoryPath(value)
@@ -78,6 +176,10 @@ impl Repository {
         }
     }
 
+    pub fn path(&self) -> &RepositoryPath {
+        &self.path
+    }
+
     pub fn files(
         &self,
     ) -> impl ParallelIterator<Item = Result<RepositoryFile, RepositoryFilesError>> {
@@ -104,6 +206,53 @@ impl Repository {
             )
     }
 
+    /// Walks the repository once and reads and parses every source file, so
+    /// analyses sharing the result do not walk or parse it again.
+    pub fn analyzed_files(&self) -> RepositoryFiles {
+        let (files, errors): (Vec<RepositoryFile>, Vec<Arc<RepositoryFilesError>>) = self
+            .files()
+            .filter(|file_result| !matches!(file_result, Err(e) if e.is_not_source_file()))
+            .partition_map(|file_result| match file_result {
+                Ok(analyzed_file) => {
+                    let _ = analyzed_file.imports();
+                    Either::Left(analyzed_file)
+                }
+                Err(e) => Either::Right(Arc::new(e)),
+            });
+
+        RepositoryFiles {
+            path: self.path.clone(),
+            files,
+            errors,
+        }
+    }
+
+    /// Locates a file given relative to the working directory or to the
+    /// repository root from its path alone, so files that no longer exist,
+    /// such as deleted ones, are located too.
+    pub fn child_path(
+        &self,
+        path: &Path,
+    ) -> Result<RepositoryChildPath, RepositoryChildPathFromPathError> {
+        let repository_path: &Path = self.path.as_ref();
+        let path = match path.canonicalize() {
+            Ok(path) => path,
+            Err(_) if path.is_absolute() => path.to_path_buf(),
+            Err(_) => match std::env::current_dir() {
+                Ok(directory) if directory.join(path).starts_with(repository_path) => {
+                    directory.join(path)
+                }
+                _ => repository_path.join(path),
+            },
+        };
+        let path: PathBuf = path
+            .components()
+            .filter(|component| *component != Component::CurDir)
+            .collect();
+
+        RepositoryChildPath::from_path(&path, &self.path)
+    }
+
     pub fn modules(&self) -> HashSet<Module> {
         self.files()
             .filter_map(|file_result| file_result.ok())
@@ -111,3 +260,31 @@ impl Repository {
             .collect()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn child_path_locates_files_that_do_not_exist() {
+        let path = std::env::temp_dir().join(format!("belmarsh-child-path-{}", std::process::id()));
+        std::fs::create_dir_all(&path).unwrap();
+        let repository = Repository::try_from(path.display().to_string()).unwrap();
+
+        let relative = repository.child_path(Path::new("./c/index.ts")).unwrap();
+        let absolute = repository
+            .child_path(&repository.path().as_ref().join("c/deleted.ts"))
+            .unwrap();
+
+        assert_eq!(relative.to_string(), "c/index.ts");
+        assert_eq!(relative.module().unwrap().to_string(), "c");
+        assert_eq!(absolute.to_string(), "c/deleted.ts");
+        assert!(
+            repository
+                .child_path(Path::new("/elsewhere/c/index.ts"))
+                .is_err()
+        );
+
+        std::fs::remove_dir_all(&path).unwrap();
+    }
+}
@@ -1,3 +1,4 @@
+use std::fmt::Display;
 use std::path::{Path, PathBuf};
 
 #[derive(Debug)]
@@ -5,6 +6,24 @@ pub enum RepositoryPathFromStringError {
     IoError(std::io::Error, String),
 }
 
+impl Display for RepositoryPathFromStringError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RepositoryPathFromStringError::IoError(_, path) => {
+                write!(f, "could not open repository path {}", path)
+            }
+        }
+    }
+}
+
+impl std::error::Error for RepositoryPathFromStringError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            RepositoryPathFromStringError::IoError(e, _) => Some(e),
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct RepositoryPath(PathBuf);
 
@@ -0,0 +1,200 @@
+use std::collections::HashMap;
+
+use lazy_static::lazy_static;
+use regex::Regex;
+
+const ALL_CHECKS: &str = "*";
+
+/// `belmarsh-ignore-next-line` and `belmarsh-ignore-file` comments of a file.
+/// Each comment lists check IDs, anything after them such as `-- reason` is
+/// ignored, and a comment without any words applies to every check. Comments
+/// are kept with the line they are written on.
+#[derive(Debug, Clone, Default)]
+pub struct Suppressions {
+    file: Vec<(usize, Vec<String>)>,
+    lines: HashMap<usize, Vec<(usize, Vec<String>)>>,
+}
+
+fn matches_check(comments: &[(usize, Vec<String>)], check_id: &str) -> bool {
+    comments
+        .iter()
+        .flat_map(|(_, check_ids)| check_ids)
+        .any(|id| id == ALL_CHECKS || id == check_id)
+}
+
+fn known_check_ids(check_ids: &mut Vec<String>, known: &[&str]) {
+    let known_count = check_ids
+        .iter()
+        .take_while(|check_id| *check_id == ALL_CHECKS || known.contains(&check_id.as_str()))
+        .count();
+
+    check_ids.truncate(known_count);
+}
+
+impl Suppressions {
+    pub fn record_line(&mut self, line_number: usize, line: &str) {
+        lazy_static! {
+            static ref SUPPRESSION_REGEX: Regex =
+                Regex::new(r"//\s*belmarsh-ignore-(next-line|file)\b(.*)")
+                    .expect("Failed to compile regex");
+        }
+
+        if let Some(captures) = SUPPRESSION_REGEX.captures(line) {
+            let mut check_ids: Vec<String> = captures
+                .get(2)
+                .map_or("", |check_ids| check_ids.as_str())
+                .split(|c: char| c.is_whitespace() || c == ',')
+                .filter(|check_id| !check_id.is_empty())
+                .take_while(|check_id| *check_id != "--")
+                .map(String::from)
+                .collect();
+
+            if check_ids.is_empty() {
+                check_ids.push(ALL_CHECKS.to_string());
+            }
+
+            if captures
+                .get(1)
+                .is_some_and(|scope| scope.as_str() == "file")
+            {
+                self.file.push((line_number, check_ids));
+            } else {
+                self.lines
+                    .entry(line_number + 1)
+                    .or_default()
+                    .push((line_number, check_ids));
+            }
+        }
+    }
+
+    /// Stops every comment at its first word that is not one of `known`, so
+    /// `circular-files because legacy` only suppresses `circular-files`.
+    /// Returns the lines of comments left without any check, which suppress
+    /// nothing.
+    pub fn retain_known_checks(&mut self, known: &[&str]) -> Vec<usize> {
+        let mut unknown_lines: Vec<usize> = vec![];
+
+        for (line_number, check_ids) in self
+            .file
+            .iter_mut()
+            .chain(self.lines.values_mut().flatten())
+        {
+            known_check_ids(check_ids, known);
+
+            if check_ids.is_empty() {
+                unknown_lines.push(*line_number);
+            }
+        }
+
+        unknown_lines.sort();
+        unknown_lines
+    }
+
+    pub fn is_file_suppressed(&self, check_id: &str) -> bool {
+        matches_check(&self.file, check_id)
+    }
+
+    pub fn is_line_suppressed(&self, check_id: &str, line: usize) -> bool {
+        self.is_file_suppressed(check_id)
+            || self
+                .lines
+                .get(&line)
+                .is_some_and(|comments| matches_check(comments, check_id))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KNOWN: [&str; 2] = ["circular-files", "external-barrel-imports"];
+
+    fn suppressions(source: &str) -> Suppressions {
+        let mut suppressions = Suppressions::default();
+
+        for (line_index, line) in source.lines().enumerate() {
+            suppressions.record_line(line_index + 1, line);
+        }
+
+        suppressions.retain_known_checks(&KNOWN);
+        suppressions
+    }
+
+    #[test]
+    fn next_line_applies_to_the_following_line_only() {
+        let suppressions =
+            suppressions("// belmarsh-ignore-next-line circular-files\nimport { A } from './a';");
+
+        assert!(suppressions.is_line_suppressed("circular-files", 2));
+        assert!(!suppressions.is_line_suppressed("circular-files", 1));
+        assert!(!suppressions.is_line_suppressed("external-barrel-imports", 2));
+    }
+
+    #[test]
+    fn file_applies_to_every_line() {
+        let suppressions = suppressions("// belmarsh-ignore-file circular-files");
+
+        assert!(suppressions.is_file_suppressed("circular-files"));
+        assert!(suppressions.is_line_suppressed("circular-files", 12));
+        assert!(!suppressions.is_file_suppressed("external-barrel-imports"));
+    }
+
+    #[test]
+    fn lists_several_checks() {
+        let suppressions =
+            suppressions("// belmarsh-ignore-file circular-files, external-barrel-imports");
+
+        assert!(suppressions.is_file_suppressed("circular-files"));
+        assert!(suppressions.is_file_suppressed("external-barrel-imports"));
+    }
+
+    #[test]
+    fn without_checks_applies_to_every_check() {
+        let suppressions = suppressions("// belmarsh-ignore-next-line");
+
+        assert!(suppressions.is_line_suppressed("circular-files", 2));
+        assert!(suppressions.is_line_suppressed("unreachable-files", 2));
+    }
+
+    #[test]
+    fn stops_at_a_reason_after_a_separator() {
+        let suppressions =
+            suppressions("// belmarsh-ignore-file circular-files -- external-barrel-imports");
+
+        assert!(suppressions.is_file_suppressed("circular-files"));
+        assert!(!suppressions.is_file_suppressed("external-barrel-imports"));
+    }
+
+    #[test]
+    fn stops_at_the_first_unknown_word() {
+        let suppressions = suppressions(
+            "// belmarsh-ignore-file circular-files because external-barrel-imports are legacy",
+        );
+
+        assert!(suppressions.is_file_suppressed("circular-files"));
+        assert!(!suppressions.is_file_suppressed("external-barrel-imports"));
+        assert!(!suppressions.is_file_suppressed("because"));
+    }
+
+    #[test]
+    fn unknown_words_alone_suppress_nothing() {
+        let mut suppressions = Suppressions::default();
+        suppressions.record_line(1, "// belmarsh-ignore-file because legacy");
+        suppressions.record_line(3, "// belmarsh-ignore-next-line external-barrel-import");
+
+        assert_eq!(suppressions.retain_known_checks(&KNOWN), vec![1, 3]);
+        assert!(!suppressions.is_file_suppressed("circular-files"));
+        assert!(!suppressions.is_line_suppressed("external-barrel-imports", 4));
+    }
+
+    #[test]
+    fn keeps_separate_comments_apart() {
+        let mut suppressions = Suppressions::default();
+        suppressions.record_line(1, "// belmarsh-ignore-file circular-files legacy");
+        suppressions.record_line(2, "// belmarsh-ignore-file external-barrel-imports");
+        suppressions.retain_known_checks(&KNOWN);
+
+        assert!(suppressions.is_file_suppressed("circular-files"));
+        assert!(suppressions.is_file_suppressed("external-barrel-imports"));
+    }
+}
@@ -1,12 +1,24 @@
 mod commands;
 
 use clap::Parser;
-use commands::validate::ValidateCommandError;
+use commands::validate::{ValidateCommandError, ValidateOutcome};
 use commands::{Cli, Commands, statistics::StatisticsCommandError};
+use std::error::Error;
+use std::fmt::Display;
+use std::process::ExitCode;
 use std::time::Instant;
 
+use crate::commands::affected::AffectedCommandError;
+use crate::commands::diff::DiffCommandError;
 use crate::commands::graph::GraphCommandError;
 use crate::commands::inspect::InspectCommandError;
+use crate::commands::layers::LayersCommandError;
+use crate::commands::matrix::MatrixCommandError;
+use crate::commands::transitive::TransitiveCommandError;
+use crate::commands::why::WhyCommandError;
+
+const EXIT_VALIDATION_FAILED: u8 = 1;
+const EXIT_TOOL_ERROR: u8 = 2;
 
 #[derive(Debug)]
 pub enum BelmarshCliError {
@@ -14,13 +26,97 @@ pub enum BelmarshCliError {
     Validate(ValidateCommandError),
     Graph(GraphCommandError),
     Inspect(InspectCommandError),
+    Why(WhyCommandError),
+    Transitive(TransitiveCommandError),
+    Affected(AffectedCommandError),
+    Diff(DiffCommandError),
+    Layers(LayersCommandError),
+    Matrix(MatrixCommandError),
 }
 
-fn main() -> Result<(), BelmarshCliError> {
-    let start = Instant::now();
+impl Display for BelmarshCliError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BelmarshCliError::Statistics(e) => write!(f, "{}", e),
+            BelmarshCliError::Validate(e) => write!(f, "{}", e),
+            BelmarshCliError::Graph(e) => write!(f, "{}", e),
+            BelmarshCliError::Inspect(e) => write!(f, "{}", e),
+            BelmarshCliError::Why(e) => write!(f, "{}", e),
+            BelmarshCliError::Transitive(e) => write!(f, "{}", e),
+            BelmarshCliError::Affected(e) => write!(f, "{}", e),
+            BelmarshCliError::Diff(e) => write!(f, "{}", e),
+            BelmarshCliError::Layers(e) => write!(f, "{}", e),
+            BelmarshCliError::Matrix(e) => write!(f, "{}", e),
+        }
+    }
+}
 
-    let cli = Cli::parse();
+impl Error for BelmarshCliError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            BelmarshCliError::Statistics(e) => e.source(),
+            BelmarshCliError::Validate(e) => e.source(),
+            BelmarshCliError::Graph(e) => e.source(),
+            BelmarshCliError::Inspect(e) => e.source(),
+            BelmarshCliError::Why(e) => e.source(),
+            BelmarshCliError::Transitive(e) => e.source(),
+            BelmarshCliError::Affected(e) => e.source(),
+            BelmarshCliError::Diff(e) => e.source(),
+            BelmarshCliError::Layers(e) => e.source(),
+            BelmarshCliError::Matrix(e) => e.source(),
+        }
+    }
+}
+
+fn print_error(error: &BelmarshCliError, verbose: bool) {
+    let mut causes: Vec<&dyn Error> = vec![];
+    let mut source = error.source();
+
+    while let Some(cause) = source {
+        causes.push(cause);
+        source = cause.source();
+    }
+
+    if verbose {
+        eprintln!("Error: {}", error);
+
+        for cause in causes.iter() {
+            eprintln!("  caused by: {}", cause);
+        }
+
+        return;
+    }
+
+    let detail = causes
+        .iter()
+        .rev()
+        .find(|cause| cause.downcast_ref::<std::io::Error>().is_none());
+    let io_error = causes
+        .last()
+        .filter(|cause| cause.downcast_ref::<std::io::Error>().is_some());
+
+    let mut message = error.to_string();
+
+    if let Some(detail) = detail {
+        message.push_str(&format!(": {}", detail));
+    }
+
+    if let Some(io_error) = io_error {
+        let io_message = io_error.to_string();
+
+        if !message.contains(&io_message) {
+            message.push_str(&format!(": {}", io_message));
+        }
+    }
 
+    eprintln!("Error: {}", message);
+
+    if causes.len() > 1 {
+        eprintln!("Run with --verbose to see the full chain of causes");
+    }
+}
+
+fn run(cli: Cli) -> Result<ExitCode, BelmarshCliError> {
     match cli.command {
         Commands::Statistics(statistics) => {
             statistics
@@ -28,14 +124,46 @@ fn main() -> Result<(), BelmarshCliError> {
                 .map_err(|e| BelmarshCliError::Statistics(e))?;
         }
         Commands::Validate(validate) => {
-            validate.run().map_err(|e| BelmarshCliError::Validate(e))?
+            match validate.run().map_err(|e| BelmarshCliError::Validate(e))? {
+                ValidateOutcome::Passed => (),
+                ValidateOutcome::Failed => return Ok(ExitCode::from(EXIT_VALIDATION_FAILED)),
+            }
         }
         Commands::Graph(graph) => graph.run().map_err(|e| BelmarshCliError::Graph(e))?,
         Commands::Inspect(inspect) => inspect.run().map_err(|e| BelmarshCliError::Inspect(e))?,
+        Commands::Why(why) => why.run().map_err(BelmarshCliError::Why)?,
+        Commands::Transitive(transitive) => {
+            transitive.run().map_err(BelmarshCliError::Transitive)?
+        }
+        Commands::Affected(affected) => affected.run().map_err(BelmarshCliError::Affected)?,
+        Commands::Diff(diff) => diff.run().map_err(BelmarshCliError::Diff)?,
+        Commands::Layers(layers) => layers.run().map_err(BelmarshCliError::Layers)?,
+        Commands::Matrix(matrix) => matrix.run().map_err(BelmarshCliError::Matrix)?,
     }
 
-    let duration = start.elapsed();
-    println!("Time elapsed: {:?}", duration);
+    Ok(ExitCode::SUCCESS)
+}
+
+fn main() -> ExitCode {
+    let start = Instant::now();
+
+    let cli = Cli::parse();
+    let timings = cli.timings;
+    let verbose = cli.verbose;
 
-    Ok(())
+    match run(cli) {
+        Ok(exit_code) => {
+            if timings {
+                let duration = start.elapsed();
+                eprintln!("Time elapsed: {:?}", duration);
+            }
+
+            exit_code
+        }
+        Err(e) => {
+            print_error(&e, verbose);
+
+            ExitCode::from(EXIT_TOOL_ERROR)
+        }
+    }
 }